@@ -0,0 +1,70 @@
+//! The command-line front end: arguments, terminal styling, and the commands the
+//! binary runs.
+
+pub mod args;
+pub mod games;
+pub mod messages;
+pub mod reports;
+pub mod solve;
+pub mod style;
+pub mod terminal;
+pub mod wordlist;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use solver_project::logging::Level;
+use solver_project::stats::{default_stats_path, ranked_stats_path};
+use solver_project::{FileLogger, WordleSolver};
+use messages::fill;
+
+// Process exit statuses, so scripts can branch on how a run ended
+pub mod exit_code {
+    /// The game was won, or the command did what it was asked
+    pub const SUCCESS: i32 = 0;
+    /// A check found problems: unsolved words, changed suggestions, bad list lines
+    pub const FAILURE: i32 = 1;
+    /// Arguments or settings that can't be used; clap also exits with 2
+    pub const USAGE: i32 = 2;
+    pub const LOST: i32 = 3;
+    /// Feedback that no word in the list fits
+    pub const INCONSISTENT: i32 = 4;
+    /// A word list that couldn't be read or had no usable words
+    pub const DICTIONARY: i32 = 5;
+}
+
+// Runs `work`, reporting on stderr how long it took when running verbose
+pub fn timed<T>(verbose: u8, what: &str, work: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = work();
+    if verbose > 0 {
+        let millis = format!("{:.1}", start.elapsed().as_secs_f64() * 1000.0);
+        eprintln!("{}", fill(messages::catalog().timing, &[&what, &millis]));
+    }
+    result
+}
+
+pub fn log_error(logger: &Option<Arc<FileLogger>>, message: &str) {
+    if let Some(logger) = logger {
+        logger.log(Level::Error, message);
+    }
+}
+
+pub fn print_pass_details(solver: &WordleSolver) {
+    let (left, label) = (solver.candidates().len(), solver.active_strategy().label());
+    eprintln!("{}", fill(messages::catalog().timing_pass, &[&left, &label]));
+}
+
+// A duration as minutes and seconds, with tenths
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let tenths = elapsed.as_millis() / 100;
+    format!("{}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
+}
+
+// The stats file named on the command line, or the default one in the data directory;
+// for ranked games, the ranked ledger beside it
+pub fn stats_path(stats_file: &Option<String>, ranked: bool) -> Option<String> {
+    let path = stats_file.as_ref().map(PathBuf::from).or_else(default_stats_path)?;
+    let path = if ranked { ranked_stats_path(&path) } else { path };
+    Some(path.to_string_lossy().into_owned())
+}
@@ -0,0 +1,460 @@
+//! Command-line arguments and the subcommands they select.
+
+use std::time::Duration;
+use clap::{Parser, Subcommand, ValueEnum};
+#[cfg(feature = "bundled-words")]
+use solver_project::load::BUNDLED_WORDS;
+#[cfg(feature = "fetch")]
+use solver_project::fetch::{UrlSource, DEFAULT_MAX_AGE};
+use solver_project::game::{Difficulty, Palette};
+use solver_project::load::{FileSource, WordSource, DEFAULT_WORD_LENGTH};
+use solver_project::logging::Level;
+use solver_project::{BeamSettings, PhaseThresholds, ProbePolicy, Strategy, TiePolicy, WordleSolver, WordleSolverBuilder};
+use super::messages;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Play two or more strategies against every word in the list and compare the results
+    Compare {
+        /// Strategies to compare
+        #[arg(value_enum, num_args = 2.., required = true)]
+        strategies: Vec<Strategy>,
+    },
+    /// Print the best next guess for a game in progress and exit
+    Suggest {
+        /// Also list this many of the best-scoring guesses by entropy
+        #[arg(long)]
+        top: Option<usize>,
+    },
+    /// Check, clean up and compare word list files
+    Wordlist {
+        #[command(subcommand)]
+        action: WordlistAction,
+    },
+    /// Play the configured strategy against every word in the list and report how it does
+    Bench,
+    /// Play randomly chosen secrets and report how the solver does
+    Simulate {
+        /// Number of games to play
+        #[arg(long, default_value_t = 100)]
+        games: usize,
+        /// Seed for picking the secrets, so runs can be repeated exactly
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Show each game being played, one guess at a time
+        #[arg(long)]
+        watch: bool,
+    },
+    /// Play today's puzzle, the same for everyone using the same word list
+    Daily {
+        /// Play the puzzle for this date (YYYY-MM-DD) instead of today's
+        #[arg(long)]
+        date: Option<String>,
+        /// UTC offset deciding when the day turns over, e.g. +05:30 or UTC
+        #[arg(long, default_value = "UTC")]
+        tz: String,
+        /// Date of puzzle number 0
+        #[arg(long, default_value = "2021-06-19")]
+        epoch: String,
+        /// Seed for the order the secrets come up in; players must share it to get the same puzzles
+        #[arg(long, default_value_t = 0)]
+        rotation: u64,
+        /// Every guess must keep the greens in place and reuse the yellows
+        #[arg(long)]
+        hard: bool,
+        /// Which words the secret is drawn from: common ones, any, or uncommon and repeated-letter ones
+        #[arg(long, value_enum, default_value_t = Difficulty::Normal)]
+        difficulty: Difficulty,
+        /// Time the game from the first prompt to the win, keeping a personal best in the stats
+        #[arg(long)]
+        timed: bool,
+        /// Allow only this many HINTs per game instead of any number
+        #[arg(long)]
+        hint_budget: Option<usize>,
+        /// Play without hints and count the game in the separate ranked stats
+        #[arg(long, conflicts_with = "hint_budget")]
+        ranked: bool,
+        /// Pick up a game saved with SAVE <file>, taking its date, epoch, rotation and mode
+        #[arg(
+            long,
+            conflicts_with_all = ["date", "epoch", "rotation", "hard", "difficulty", "timed", "hint_budget", "ranked"]
+        )]
+        resume: Option<String>,
+    },
+    /// Two players: one types the secret without it showing, the other guesses it
+    Friend,
+    /// Race the solver to the same secret, taking turns; the solver's letters stay hidden
+    Duel {
+        /// Seed for picking the secret, so a duel can be replayed exactly
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Play game after game against random secrets until the first loss, keeping a streak
+    Endless {
+        /// Seed for picking the secrets, so a run can be replayed exactly
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Play several boards at once with the same guesses, like Dordle (2) or Quordle (4)
+    Multi {
+        /// How many boards to play
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u8).range(2..=8))]
+        boards: u8,
+        /// Seed for picking the secrets, so a game can be replayed exactly
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Get suggestions for a Dordle or Quordle style game: one guess for every board,
+    /// from the results on each
+    SolveMulti {
+        /// How many boards the game has
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u8).range(2..=8))]
+        boards: u8,
+    },
+    /// Play a practice game against a word from a drill's pool; it doesn't count toward the stats
+    Practice {
+        /// Which kind of word to practice on
+        #[arg(long, value_enum, default_value_t = Drill::Repeats)]
+        drill: Drill,
+        /// Seed for picking the secret, so a drill can be repeated exactly
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Show games played, win rate, guess distribution and streaks from finished daily games
+    Stats {
+        /// Show the ranked games' stats instead of the casual ones
+        #[arg(long)]
+        ranked: bool,
+    },
+    /// List finished daily games, or step through one turn by turn with the solver's commentary
+    Review {
+        /// Number of the game to step through, as listed; without it, list the games
+        game: Option<usize>,
+        /// Review ranked games instead of casual ones
+        #[arg(long)]
+        ranked: bool,
+    },
+    /// Time the main computations on the loaded word list, for measuring optimizations
+    Profile {
+        /// How many times to run each computation
+        #[arg(long, default_value_t = 5)]
+        runs: usize,
+    },
+    /// List the words matching a pattern like _a_le, where _ or ? stands for any letter
+    Search {
+        pattern: String,
+        /// Letters the word must not contain
+        #[arg(long, default_value = "")]
+        exclude: String,
+        /// Letters the word must contain somewhere
+        #[arg(long, default_value = "")]
+        include: String,
+    },
+    /// Grade each turn of a game given with --history against the best guess available then
+    Analyze,
+    /// Re-drive the solver through a transcript saved with TRANSCRIPT and check it still makes the same suggestions
+    Replay {
+        /// Transcript file to replay
+        transcript: String,
+    },
+}
+
+// Pools of tricky secrets for practice games
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Drill {
+    /// Words that repeat a letter
+    Repeats,
+    /// Words in a family differing in one letter, like batch/catch/hatch
+    Traps,
+    /// Secrets of daily games you lost
+    Missed,
+}
+
+impl Drill {
+    pub fn label(self) -> &'static str {
+        let messages = messages::catalog();
+        match self {
+            Drill::Repeats => messages.drill_repeats,
+            Drill::Traps => messages.drill_traps,
+            Drill::Missed => messages.drill_missed,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub enum WordlistAction {
+    /// Report every line that loading would skip or that repeats an earlier word
+    Validate {
+        file: String,
+    },
+    /// Write the usable words of a list, each once
+    Dedupe {
+        file: String,
+        /// Write here instead of to stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Combine lists, keeping the first copy of each word
+    Merge {
+        #[arg(num_args = 2.., required = true)]
+        files: Vec<String>,
+        /// Write here instead of to stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Show the words only in the first list (-) or only in the second (+)
+    Diff {
+        old: String,
+        new: String,
+    },
+    /// Summarize a list: letter frequencies, repeated letters and its best openers
+    Stats {
+        file: String,
+        /// How many openers to list
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+    },
+}
+
+#[derive(Parser)]
+#[command(
+    name = "wordle_solver",
+    about = "Interactive Wordle solver",
+    after_help = "Exit status: 0 won or succeeded, 1 check failed, 2 invalid arguments, 3 lost, \
+                  4 feedback no word fits, 5 word list couldn't be loaded"
+)]
+pub struct Args {
+    /// Path or http(s) URL of a list with one candidate word per line, optionally followed by a tab
+    /// and a weight; defaults to a built-in list of common five-letter words
+    #[arg(env = "WORDLE_SOLVER_WORDLIST")]
+    pub wordlist_path: Option<String>,
+
+    /// Treat common English words as likelier to be the answer, as in the official puzzle, by this factor times their frequency weight
+    #[arg(long)]
+    pub common_bias: Option<f64>,
+
+    /// Number of letters per word; words of any other length are skipped when loading
+    #[arg(long, default_value_t = DEFAULT_WORD_LENGTH)]
+    pub length: usize,
+
+    /// Optional list of extra words allowed as guesses but never the answer, as a path or URL
+    #[arg(long)]
+    pub guesses: Option<String>,
+
+    /// Ask the server whether a downloaded word list changed, even if the cached copy is recent
+    #[arg(long)]
+    pub refresh: bool,
+
+    /// How the solver picks its next guess
+    #[arg(long, value_enum, default_value_t = Strategy::Entropy, env = "WORDLE_SOLVER_STRATEGY")]
+    pub strategy: Strategy,
+
+    /// With --strategy beam, how many guesses to keep at each ply
+    #[arg(long, default_value_t = BeamSettings::default().width)]
+    pub beam_width: usize,
+
+    /// With --strategy beam, how many plies to look ahead
+    #[arg(long, default_value_t = BeamSettings::default().depth)]
+    pub beam_depth: usize,
+
+    /// Switch to expected-guess and then worst-case search as the candidates dwindle
+    #[arg(long)]
+    pub adaptive: bool,
+
+    /// With --adaptive, minimize expected guesses below this many candidates
+    #[arg(long, default_value_t = 50)]
+    pub expected_below: usize,
+
+    /// With --adaptive, search for a worst-case guarantee below this many candidates
+    #[arg(long, default_value_t = 10)]
+    pub guarantee_below: usize,
+
+    /// Entropy difference below which two guesses count as tied
+    #[arg(long, default_value_t = 1e-10)]
+    pub tie_epsilon: f64,
+
+    /// What to do when several guesses tie for best
+    #[arg(long, value_enum, default_value_t = TiePolicy::First)]
+    pub tie_policy: TiePolicy,
+
+    /// When guesses that can't be the answer may be suggested
+    #[arg(long, value_enum, default_value_t = ProbePolicy::NotLast)]
+    pub probes: ProbePolicy,
+
+    /// Always open with this word, in play and in simulations, instead of the strategy's pick
+    #[arg(long)]
+    pub opener: Option<String>,
+
+    /// Seed for the rollout strategy's random play, so runs can be repeated exactly
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Only make guesses that keep every green in place and reuse every yellow
+    #[arg(long)]
+    pub hard: bool,
+
+    /// Number of threads for scoring guesses; defaults to one per CPU
+    #[arg(long, env = "WORDLE_SOLVER_THREADS")]
+    pub threads: Option<usize>,
+
+    /// Below --exact-below candidates, guess the candidate with the fewest expected guesses by exhaustive
+    /// search over the candidates alone; probes that could not be the answer are never weighed
+    #[arg(long)]
+    pub exact: bool,
+
+    /// With --exact, how few candidates make the exhaustive search worthwhile; it grows exponentially
+    #[arg(long, default_value_t = 20)]
+    pub exact_below: usize,
+
+    /// Pick up a game saved with SAVE, played with the same word list
+    #[arg(long, conflicts_with_all = ["history", "history_file"])]
+    pub resume: Option<String>,
+
+    /// Enter each guess's results by coloring its tiles with the arrow keys and G, Y and B
+    /// instead of typing them; Esc switches to typing for a command
+    #[arg(long)]
+    pub picker: bool,
+
+    /// Turns already played, as comma-separated guess=results pairs, e.g. "crane=BYBBG,slimy=BBGYB"
+    #[arg(long, global = true)]
+    pub history: Option<String>,
+
+    /// Read the turns already played from a file, one guess=results pair per line
+    #[arg(long, global = true, conflicts_with = "history")]
+    pub history_file: Option<String>,
+
+    /// Play the strategy against every word in the list and report any it fails to solve
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Report how long loading and each scoring pass take on stderr; repeat (-vv) for more detail
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    /// Append the solver's suggestions, applied turns and errors to this file
+    #[arg(long, global = true)]
+    pub log_file: Option<String>,
+
+    /// Least important messages written to --log-file
+    #[arg(long, global = true, value_enum, default_value_t = Level::Debug)]
+    pub log_level: Level,
+
+    /// Draw tiles without color; also off when NO_COLOR is set or stdout isn't a terminal
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Show each guess's tiles at once instead of revealing them one by one; the reveal
+    /// is also off when stdout isn't a terminal
+    #[arg(long, global = true, env = "WORDLE_SOLVER_NO_ANIMATION")]
+    pub no_animation: bool,
+
+    /// File keeping the daily game's statistics; defaults to stats.txt in the user's data directory
+    #[arg(long, global = true, env = "WORDLE_SOLVER_STATS_FILE")]
+    pub stats_file: Option<String>,
+
+    /// Tile colors; color-blind uses orange and blue. PALETTE switches it while playing
+    #[arg(long, global = true, value_enum, default_value_t = Palette::Standard, env = "WORDLE_SOLVER_PALETTE")]
+    pub palette: Palette,
+
+    /// Language of prompts and messages; commands can also be typed in it, e.g. ESTADO for STATUS
+    #[arg(long, global = true, value_enum, default_value_t = messages::Language::En, env = "WORDLE_SOLVER_LANG")]
+    pub lang: messages::Language,
+
+    /// Print the results of every non-interactive command, and of --verify, as JSON on stdout, with progress on stderr
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+impl Args {
+    /// The solver builder the word list, strategy and tuning options ask for.
+    pub fn solver_builder(&self) -> WordleSolverBuilder {
+        let mut builder = match &self.wordlist_path {
+            Some(location) => WordleSolver::builder_from(word_source(location, self.refresh)),
+            None => default_builder(),
+        };
+        builder = builder
+            .word_length(self.length)
+            .strategy(self.strategy)
+            .beam(BeamSettings { width: self.beam_width, depth: self.beam_depth })
+            .tie_epsilon(self.tie_epsilon)
+            .tie_policy(self.tie_policy)
+            .probe_policy(self.probes)
+            .hard_mode(self.hard);
+        if let Some(guesses) = &self.guesses {
+            builder = builder.guesses_from(word_source(guesses, self.refresh));
+        }
+        if let Some(factor) = self.common_bias {
+            builder = builder.common_bias(factor);
+        }
+        if self.exact {
+            builder = builder.exact_below(self.exact_below);
+        }
+        if self.adaptive {
+            builder = builder.phase_thresholds(PhaseThresholds {
+                expected_below: self.expected_below,
+                guarantee_below: self.guarantee_below,
+            });
+        }
+        if let Some(opener) = &self.opener {
+            builder = builder.opener(opener);
+        }
+        if let Some(seed) = self.seed {
+            builder = builder.seed(seed);
+        }
+        if let Some(threads) = self.threads {
+            builder = builder.threads(threads);
+        }
+        builder
+    }
+}
+
+// Word lists can be file paths or, with the fetch feature, URLs
+#[cfg_attr(not(feature = "fetch"), allow(unused_variables))]
+pub fn word_source(location: &str, refresh: bool) -> Box<dyn WordSource> {
+    #[cfg(feature = "fetch")]
+    if UrlSource::is_url(location) {
+        let max_age = if refresh { Duration::ZERO } else { DEFAULT_MAX_AGE };
+        return Box::new(UrlSource { url: location.to_string(), max_age });
+    }
+    Box::new(FileSource(location.to_string()))
+}
+
+#[cfg(feature = "bundled-words")]
+pub fn default_builder() -> WordleSolverBuilder {
+    WordleSolver::builder_from(BUNDLED_WORDS)
+}
+
+#[cfg(not(feature = "bundled-words"))]
+pub fn default_builder() -> WordleSolverBuilder {
+    eprintln!("{}", messages::catalog().no_word_list);
+    std::process::exit(exit_code::USAGE);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn environment_fills_in_arguments_not_given() {
+        std::env::set_var("WORDLE_SOLVER_WORDLIST", "env-words.txt");
+        std::env::set_var("WORDLE_SOLVER_STRATEGY", "fast");
+        std::env::set_var("WORDLE_SOLVER_THREADS", "3");
+        let from_env = Args::try_parse_from(["wordle_solver"]);
+        let given = Args::try_parse_from(["wordle_solver", "words.txt", "--strategy", "beam", "--threads", "2"]);
+        for name in ["WORDLE_SOLVER_WORDLIST", "WORDLE_SOLVER_STRATEGY", "WORDLE_SOLVER_THREADS"] {
+            std::env::remove_var(name);
+        }
+
+        let from_env = from_env.unwrap();
+        assert_eq!(
+            (from_env.wordlist_path.as_deref(), from_env.strategy, from_env.threads),
+            (Some("env-words.txt"), Strategy::Fast, Some(3))
+        );
+        // Arguments on the command line win over the environment
+        let given = given.unwrap();
+        assert_eq!((given.wordlist_path.as_deref(), given.strategy, given.threads), (Some("words.txt"), Strategy::Beam, Some(2)));
+    }
+}
@@ -0,0 +1,646 @@
+//! Games against a hidden word: daily puzzles, multi-board, endless, duels, practice
+//! drills and playing against a friend.
+
+use std::time::Instant;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use solver_project::constraints::LetterState;
+use solver_project::clock::{day_number, format_date, parse_date, parse_utc_offset, Clock};
+use solver_project::game::{Difficulty, MultiGame, Palette, SavedGame};
+use solver_project::oracle::simulate_results;
+use solver_project::{DailySettings, Game, GameStatus, MultiSolver, PlayerStats, SolverEngine, WordleSolver};
+use super::messages::{self, fill};
+use super::{exit_code, style};
+use super::args::Drill;
+use super::{format_elapsed, stats_path};
+use super::reports::print_player_stats;
+use super::terminal::{command_argument, read_command, read_hidden_line};
+
+// Which daily puzzle to play and how, as given on the command line
+pub struct DailyArgs<'a> {
+    pub date: Option<&'a str>,
+    pub tz: &'a str,
+    pub epoch: &'a str,
+    pub rotation: u64,
+    pub hard_mode: bool,
+    pub difficulty: Difficulty,
+    pub timed: bool,
+    pub hint_budget: Option<usize>,
+    pub ranked: bool,
+}
+
+// Works out the puzzle for the day and lets the player guess it; returns the exit status.
+// Today comes from `clock`, and the stats count the game on the puzzle's day
+pub fn play_daily(solver: &WordleSolver, args: DailyArgs, resume: Option<&str>, stats_file: &Option<String>, clock: &dyn Clock) -> i32 {
+    let messages = messages::catalog();
+    let saved = match resume.map(SavedGame::read).transpose() {
+        Ok(saved) => saved,
+        Err(e) => {
+            eprintln!("{}", fill(messages.resume_game_failed, &[&e]));
+            return exit_code::USAGE;
+        }
+    };
+    let Some(utc_offset) = parse_utc_offset(args.tz) else {
+        eprintln!("{}", fill(messages.invalid_tz, &[&args.tz]));
+        return exit_code::USAGE;
+    };
+    // A saved game keeps the puzzle and rules it was started with
+    let settings = match &saved {
+        Some(saved) => DailySettings::from_saved(saved),
+        None => {
+            let day = match args.date {
+                Some(date) => parse_date(date),
+                None => Some(day_number(clock.now(), utc_offset)),
+            };
+            day.zip(parse_date(args.epoch)).map(|(day, epoch_day)| DailySettings {
+                day,
+                epoch_day,
+                rotation: args.rotation,
+                hard_mode: args.hard_mode,
+                difficulty: args.difficulty,
+                hint_budget: args.hint_budget,
+                ranked: args.ranked,
+            })
+        }
+    };
+    let Some(settings) = settings else {
+        eprintln!("{}", messages.invalid_date);
+        return exit_code::USAGE;
+    };
+    // Ranked games get their own ledger, leaving casual stats untouched
+    let stats_file = stats_path(stats_file, settings.ranked);
+    let Some(secret) = settings.secret(solver) else {
+        eprintln!("{}", messages.no_candidates);
+        return exit_code::DICTIONARY;
+    };
+
+    let mut game = settings.new_game(solver, &secret);
+    let mut modes = String::new();
+    if settings.hard_mode {
+        modes.push_str(messages.mode_hard);
+    }
+    match settings.difficulty {
+        Difficulty::Easy => modes.push_str(&fill(messages.mode_difficulty, &[&messages.difficulty_easy])),
+        Difficulty::Normal => {}
+        Difficulty::Hard => modes.push_str(&fill(messages.mode_difficulty, &[&messages.difficulty_hard])),
+    }
+    if settings.ranked {
+        modes.push_str(messages.mode_ranked);
+    }
+    let (puzzle, length, tries) = (settings.puzzle(), game.word_length(), game.max_attempts());
+    println!("{}", fill(messages.daily_intro, &[&puzzle, &format_date(settings.day), &modes, &length, &tries]));
+    if let Some(saved) = &saved {
+        if let Err(e) = settings.resume(&mut game, saved) {
+            eprintln!("{}", fill(messages.saved_game_mismatch, &[&e]));
+            return exit_code::USAGE;
+        }
+        for (guess, feedback) in game.turns() {
+            println!("  {}", style::tiles(guess, feedback.as_str()));
+        }
+    }
+    let start = Instant::now();
+    while game.status() == GameStatus::Ongoing {
+        let (turn, tries) = (game.turns().len() + 1, game.max_attempts());
+        if args.timed {
+            println!("{}", fill(messages.guess_prompt_timed, &[&turn, &tries, &format_elapsed(start.elapsed())]));
+        } else {
+            println!("{}", fill(messages.guess_prompt, &[&turn, &tries]));
+        }
+        let Some(input) = read_command() else {
+            println!("{}", fill(messages.word_was, &[&game.secret()]));
+            return exit_code::LOST;
+        };
+        if input.eq_ignore_ascii_case("SHARE") {
+            println!("{}", game.share_grid(puzzle, style::palette()));
+            continue;
+        }
+        if input.eq_ignore_ascii_case("GIVEUP") {
+            give_up(solver, &mut game);
+            break;
+        }
+        if input.eq_ignore_ascii_case("PALETTE") {
+            switch_palette();
+            continue;
+        }
+        if let Some(level) = hint_level(&input) {
+            give_hint(solver, &mut game, level, settings.hints_allowed());
+            continue;
+        }
+        if let Some(path) = command_argument(&input, "SAVE") {
+            match settings.save(&game).write(path) {
+                Ok(()) => println!("{}", fill(messages.daily_saved, &[&path, &path])),
+                Err(e) => println!("{}", fill(messages.save_failed, &[&e])),
+            }
+            continue;
+        }
+        play_guess(&mut game, &input);
+    }
+
+    let won = game.status() == GameStatus::Won;
+    let time = (args.timed && won).then(|| start.elapsed());
+    let (guesses, tries) = (game.turns().len(), game.max_attempts());
+    if let Some(time) = time {
+        println!("{}", fill(messages.daily_solved_timed, &[&puzzle, &guesses, &tries, &format_elapsed(time)]));
+    } else if won {
+        println!("{}", fill(messages.daily_solved, &[&puzzle, &guesses, &tries]));
+    } else if !game.given_up() {
+        println!("{}", fill(messages.out_of_guesses_word, &[&game.secret()]));
+    }
+    println!("\n{}", game.share_grid(puzzle, style::palette()));
+    print_solver_comparison(solver, &game);
+    // Only finished games count; giving up with EXIT leaves the stats alone
+    if let Some(path) = &stats_file {
+        match settings.record_stats(path, &game, time) {
+            Ok((stats, personal_best)) => {
+                if personal_best {
+                    println!("{}", messages.personal_best);
+                }
+                println!();
+                print_player_stats(&stats, false);
+            }
+            Err(e) => eprintln!("{}", fill(messages.stats_update_failed, &[&e])),
+        }
+        if let Err(e) = settings.log_game(path, &game) {
+            eprintln!("{}", fill(messages.game_log_failed, &[&e]));
+        }
+    }
+    if won { exit_code::SUCCESS } else { exit_code::LOST }
+}
+
+// Gives a hint of the requested level, if the hint budget allows another: 1 says how
+// many words are still possible, 2 names a letter of the secret not yet found, and
+// 3 shows the solver's pick for the game so far
+pub fn give_hint(solver: &WordleSolver, game: &mut Game, level: u8, budget: Option<usize>) {
+    let messages = messages::catalog();
+    if budget == Some(0) {
+        println!("{}", messages.hints_off);
+        return;
+    }
+    if budget.is_some_and(|budget| game.hints_used() >= budget) {
+        println!("{}", fill(messages.hints_used_up, &[&budget.unwrap_or_default()]));
+        return;
+    }
+    let hint = match level {
+        1 => match solver.candidates().iter().filter(|word| game.constraints().allows(word)).count() {
+            1 => messages.hint_word_left.to_string(),
+            left => fill(messages.hint_words_left, &[&left]),
+        },
+        2 => {
+            let found = |letter: char| game.constraints().letter_state(letter) >= LetterState::Present;
+            match game.secret().chars().find(|&letter| !found(letter)) {
+                Some(letter) => fill(messages.hint_letter, &[&letter.to_ascii_uppercase()]),
+                None => {
+                    println!("{}", messages.hint_all_found);
+                    return;
+                }
+            }
+        }
+        3 => {
+            let mut hinter = solver.clone();
+            hinter.start();
+            if game.turns().iter().any(|(guess, feedback)| hinter.apply(guess, feedback.as_str()).is_err()) {
+                println!("{}", messages.hint_lost_track);
+                return;
+            }
+            let Some(guess) = hinter.suggest().map(str::to_string) else { return };
+            fill(messages.hint_try, &[&guess])
+        }
+        _ => {
+            println!("{}", messages.hint_levels);
+            return;
+        }
+    };
+    game.record_hint();
+    match budget {
+        Some(budget) => println!("{}", fill(messages.hint_budget, &[&hint, &game.hints_used(), &budget])),
+        None => println!("{}", hint),
+    }
+}
+
+// Reads HINT, HINT 2 and the like as a hint level (0 if unreadable), or None for any
+// other input
+pub fn hint_level(input: &str) -> Option<u8> {
+    if input.eq_ignore_ascii_case("HINT") {
+        return Some(1);
+    }
+    command_argument(input, "HINT").map(|level| level.parse().unwrap_or(0))
+}
+
+// Plays several boards with shared guesses. Each extra board adds one guess to the
+// budget, so two boards get 7 tries and four get 9 as in Dordle and Quordle
+pub fn play_multi(solver: &WordleSolver, boards: usize, seed: Option<u64>) -> i32 {
+    let messages = messages::catalog();
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let secrets: Vec<String> = solver.candidates().choose_multiple(&mut rng, boards).cloned().collect();
+    if secrets.len() < boards {
+        eprintln!("{}", fill(messages.too_few_candidates, &[&boards]));
+        return exit_code::DICTIONARY;
+    }
+    let mut game = MultiGame::new(&secrets, solver.allowed_guesses(), solver.max_attempts + boards - 1);
+    println!("{}", fill(messages.multi_intro, &[&boards, &game.max_attempts()]));
+    let width = solver.word_length() * 3;
+    while game.status() == GameStatus::Ongoing {
+        println!("{}", fill(messages.guess_prompt, &[&(game.guesses() + 1), &game.max_attempts()]));
+        let Some(input) = read_command() else { break };
+        if let Err(e) = game.guess(&input.to_lowercase()) {
+            println!("{}", fill(messages.try_another, &[&e]));
+            continue;
+        }
+        // Boards side by side; a solved board stays blank below its winning row
+        for turn in 0..game.guesses() {
+            let row: Vec<String> = game
+                .boards()
+                .iter()
+                .map(|board| match board.turns().get(turn) {
+                    Some((guess, feedback)) => style::tiles(guess, feedback.as_str()),
+                    None => " ".repeat(width),
+                })
+                .collect();
+            println!("  {}", row.join("  "));
+        }
+        let solved = game.boards().iter().filter(|board| board.status() == GameStatus::Won).count();
+        println!("{}", fill(messages.boards_solved, &[&solved, &boards]));
+    }
+
+    if game.status() == GameStatus::Won {
+        println!("{}", fill(messages.multi_won, &[&boards, &game.guesses(), &game.max_attempts()]));
+        exit_code::SUCCESS
+    } else {
+        let secrets: Vec<&str> = game.boards().iter().map(|board| board.secret()).collect();
+        println!("{}", fill(messages.words_were, &[&secrets.join(", ")]));
+        exit_code::LOST
+    }
+}
+
+// Suggests guesses for a multi-board game, taking the results of each guess on every
+// board still unsolved; returns the exit status
+pub fn solve_multi(solver: &WordleSolver, boards: usize) -> i32 {
+    let messages = messages::catalog();
+    let mut multi = MultiSolver::new(solver, boards);
+    println!("{}", fill(messages.solve_multi_intro, &[&boards, &multi.max_attempts()]));
+    while multi.status() == GameStatus::Ongoing {
+        let Some(suggestion) = multi.make_guess() else {
+            println!("{}", messages.multi_no_fit);
+            return exit_code::INCONSISTENT;
+        };
+        let unsolved: Vec<String> = multi.unsolved().iter().map(|idx| (idx + 1).to_string()).collect();
+        println!("{}", fill(messages.multi_suggestion, &[&(multi.guesses() + 1), &multi.max_attempts(), &suggestion]));
+        match unsolved.as_slice() {
+            [board] => println!("{}", fill(messages.enter_board_results, &[board])),
+            boards => println!("{}", fill(messages.enter_boards_results, &[&boards.join(", ")])),
+        }
+        let Some(input) = read_command() else { return exit_code::FAILURE };
+        let (guess, results) = match command_argument(&input, "GUESS") {
+            Some(turn) => match turn.split_once(char::is_whitespace) {
+                Some((word, results)) => (word.to_lowercase(), results),
+                None => {
+                    println!("{}", messages.multi_guess_usage);
+                    continue;
+                }
+            },
+            None => (suggestion, input.as_str()),
+        };
+        let results: Vec<&str> = results.split_whitespace().collect();
+        if results.len() != unsolved.len() {
+            println!("{}", fill(messages.multi_result_count, &[&unsolved.len()]));
+            continue;
+        }
+        if let Err(e) = multi.apply(&guess, &results) {
+            println!("{}", fill(messages.invalid_results, &[&e]));
+            continue;
+        }
+        for (idx, board) in multi.boards().iter().enumerate() {
+            match board.status() {
+                GameStatus::Won => println!("{}", fill(messages.board_solved, &[&(idx + 1)])),
+                _ => println!("{}", fill(messages.board_left, &[&(idx + 1), &board.candidates().len()])),
+            }
+        }
+    }
+    if multi.status() == GameStatus::Won {
+        println!("{}", fill(messages.multi_solved, &[&boards, &multi.guesses(), &multi.max_attempts()]));
+        exit_code::SUCCESS
+    } else {
+        println!("{}", messages.out_of_guesses);
+        exit_code::LOST
+    }
+}
+
+// Plays games back to back on the one loaded word list, starting the next as soon as
+// one is won, until a game is lost or abandoned
+pub fn play_endless(solver: &WordleSolver, seed: Option<u64>) -> i32 {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let messages = messages::catalog();
+    let mut streak = 0;
+    let mut total_guesses = 0;
+    println!("{}", fill(messages.endless_intro, &[&solver.max_attempts]));
+    let code = loop {
+        let Some(secret) = solver.candidates().choose(&mut rng) else {
+            eprintln!("{}", messages.no_candidates);
+            return exit_code::DICTIONARY;
+        };
+        let mut game = Game::new(secret, solver.allowed_guesses().iter().cloned(), solver.max_attempts);
+        println!("{}", fill(messages.endless_word, &[&(streak + 1), &streak]));
+        while game.status() == GameStatus::Ongoing {
+            println!("{}", fill(messages.guess_prompt, &[&(game.turns().len() + 1), &game.max_attempts()]));
+            let Some(input) = read_command() else { break };
+            play_guess(&mut game, &input);
+        }
+        if game.status() != GameStatus::Won {
+            println!("{}", fill(messages.word_was, &[secret]));
+            break exit_code::LOST;
+        }
+        streak += 1;
+        total_guesses += game.turns().len();
+        println!("{}", fill(messages.endless_solved, &[&game.turns().len(), &game.max_attempts(), &streak]));
+    };
+
+    let average = format!("{:.2}", total_guesses as f64 / streak.max(1) as f64);
+    match streak {
+        0 => println!("{}", messages.endless_over_none),
+        1 => println!("{}", fill(messages.endless_over_one, &[&average])),
+        _ => println!("{}", fill(messages.endless_over, &[&streak, &average])),
+    }
+    code
+}
+
+// The player and the solver take turns guessing the same secret; whoever solves it
+// first wins. The solver's row shows only its colors, so its deductions stay hidden
+pub fn play_duel(solver: &WordleSolver, seed: Option<u64>) -> i32 {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let messages = messages::catalog();
+    let Some(secret) = solver.candidates().choose(&mut rng) else {
+        eprintln!("{}", messages.no_candidates);
+        return exit_code::DICTIONARY;
+    };
+    let allowed = || solver.allowed_guesses().iter().cloned();
+    let (mut player, mut rival) = (Game::new(secret, allowed(), solver.max_attempts), Game::new(secret, allowed(), solver.max_attempts));
+    let mut opponent = solver.clone();
+    opponent.start();
+
+    println!("{}", fill(messages.duel_intro, &[&player.max_attempts(), &player.word_length()]));
+    let hidden = |guess: &str| " ".repeat(guess.len());
+    while player.status() == GameStatus::Ongoing || rival.status() == GameStatus::Ongoing {
+        if player.status() == GameStatus::Ongoing {
+            println!("{}", fill(messages.duel_prompt, &[&(player.turns().len() + 1), &player.max_attempts()]));
+            let Some(input) = read_command() else {
+                println!("{}", fill(messages.duel_gave_up, &[secret]));
+                return exit_code::LOST;
+            };
+            if let Err(e) = player.guess(&input.to_lowercase()) {
+                println!("{}", fill(messages.try_another, &[&e]));
+                continue;
+            }
+        }
+        if rival.status() == GameStatus::Ongoing && player.status() != GameStatus::Won {
+            if let Some(guess) = opponent.current_guess().map(str::to_string) {
+                let feedback = rival.guess(&guess).map(|feedback| feedback.as_str().to_string());
+                if let Ok(feedback) = feedback {
+                    // The answer is on the list, so its feedback always fits
+                    let _ = opponent.next_turn(&feedback);
+                }
+            }
+        }
+
+        // Both boards side by side, one row per turn
+        let width = secret.len() * 3;
+        println!("  {:<width$}    {}", messages.heading_you, messages.heading_solver);
+        for turn in 0..player.turns().len().max(rival.turns().len()) {
+            let mine = player.turns().get(turn).map(|(guess, feedback)| style::tiles(guess, feedback.as_str()));
+            let theirs = rival.turns().get(turn).map(|(guess, feedback)| style::tiles(&hidden(guess), feedback.as_str()));
+            let mine = mine.unwrap_or_else(|| " ".repeat(width));
+            println!("  {}    {}", mine, theirs.unwrap_or_default());
+        }
+        if player.status() == GameStatus::Won || rival.status() == GameStatus::Won {
+            break;
+        }
+    }
+
+    match (player.status(), rival.status()) {
+        (GameStatus::Won, _) => {
+            println!("{}", fill(messages.duel_won, &[secret, &player.turns().len()]));
+            exit_code::SUCCESS
+        }
+        (_, GameStatus::Won) => {
+            println!("{}", fill(messages.duel_lost, &[&rival.turns().len(), secret]));
+            exit_code::LOST
+        }
+        _ => {
+            println!("{}", fill(messages.duel_draw, &[secret]));
+            exit_code::LOST
+        }
+    }
+}
+
+// Ends the game and turns it into a lesson: the secret, the words that still fit,
+// and the guesses that fell furthest short of the best one available at the time
+pub fn give_up(solver: &WordleSolver, game: &mut Game) {
+    let messages = messages::catalog();
+    game.give_up();
+    println!("{}", fill(messages.word_was, &[&game.secret()]));
+
+    let left: Vec<&String> = solver.candidates().iter().filter(|word| game.constraints().allows(word)).collect();
+    const SHOWN: usize = 20;
+    let shown: Vec<&str> = left.iter().take(SHOWN).map(|word| word.as_str()).collect();
+    let more = if left.len() > SHOWN { fill(messages.and_more, &[&(left.len() - SHOWN)]) } else { String::new() };
+    println!("{}", fill(messages.still_fit, &[&left.len(), &shown.join(", "), &more]));
+
+    // Information given up on each turn, against the best guess at that point
+    let mut replay = solver.clone();
+    let mut shortfalls = Vec::new();
+    for (guess, feedback) in game.turns() {
+        let best = replay.scored_guesses().into_iter().next();
+        if replay.apply(guess, feedback.as_str()).is_err() {
+            break;
+        }
+        let turn = replay.turns().last().unwrap();
+        if let Some(best) = best.filter(|best| best.entropy > turn.expected_information + 0.01) {
+            shortfalls.push((turn.guess.clone(), turn.expected_information, best.word, best.entropy));
+        }
+    }
+    shortfalls.sort_by(|a, b| (b.3 - b.1).partial_cmp(&(a.3 - a.1)).unwrap());
+    if shortfalls.is_empty() {
+        println!("{}", messages.no_shortfall);
+    }
+    for (guess, bits, best, best_bits) in shortfalls.iter().take(3) {
+        let (bits, best_bits) = (format!("{:.2}", bits.max(0.0)), format!("{:.2}", best_bits));
+        println!("{}", fill(messages.shortfall, &[guess, &bits, best, &best_bits]));
+    }
+}
+
+// Shows how the solver would have played the finished game's secret, from the
+// player's opener and, if it would have opened differently, from its own
+pub fn print_solver_comparison(solver: &WordleSolver, game: &Game) {
+    let Some((player_opener, _)) = game.turns().first() else { return };
+    let player_score = match game.status() {
+        GameStatus::Won => game.turns().len().to_string(),
+        _ => "X".to_string(),
+    };
+    let messages = messages::catalog();
+    let mut fresh = solver.clone();
+    let own_opener = fresh.start().map(str::to_string);
+    let mut openers = vec![(player_opener.clone(), messages.solver_from_your_opener)];
+    if let Some(own_opener) = own_opener.filter(|opener| opener != player_opener) {
+        openers.push((own_opener, messages.solver_own_opener));
+    }
+    for (opener, heading) in openers {
+        let (guesses, won) = solver.play_out(game.secret(), &opener);
+        println!("{}", heading);
+        for guess in &guesses {
+            println!("  {}", style::tiles(guess, &simulate_results(guess, game.secret())));
+        }
+        let score = if won { guesses.len().to_string() } else { "X".to_string() };
+        println!("{}", fill(messages.against_yours, &[&score, &solver.max_attempts, &player_score, &game.max_attempts()]));
+    }
+}
+
+// Swaps between the standard and color-blind tile colors
+pub fn switch_palette() {
+    let palette = match style::palette() {
+        Palette::Standard => Palette::ColorBlind,
+        Palette::ColorBlind => Palette::Standard,
+    };
+    style::set_palette(palette);
+    let messages = messages::catalog();
+    let name = match palette {
+        Palette::Standard => messages.palette_standard,
+        Palette::ColorBlind => messages.palette_color_blind,
+    };
+    println!("{}", fill(messages.palette_now, &[&name]));
+}
+
+// Lets one player set the secret without it showing, clears the screen and lets a
+// second player guess it; returns the exit status
+pub fn play_friend(solver: &WordleSolver) -> i32 {
+    let messages = messages::catalog();
+    let secret = loop {
+        println!("{}", fill(messages.friend_secret, &[&solver.word_length()]));
+        let Some(input) = read_hidden_line() else { return exit_code::USAGE };
+        let word = input.trim().to_lowercase();
+        if word.chars().count() != solver.word_length() || !word.chars().all(|letter| letter.is_ascii_alphabetic()) {
+            println!("{}", fill(messages.not_a_word, &[&solver.word_length()]));
+        } else if !solver.allowed_guesses().contains(&word) {
+            println!("{}", messages.not_in_list);
+        } else {
+            break word;
+        }
+    };
+    style::clear_screen();
+
+    let mut game = Game::new(&secret, solver.allowed_guesses().iter().cloned(), solver.max_attempts);
+    println!("{}", fill(messages.friend_intro, &[&game.word_length(), &game.max_attempts()]));
+    while game.status() == GameStatus::Ongoing {
+        println!("{}", fill(messages.guess_prompt, &[&(game.turns().len() + 1), &game.max_attempts()]));
+        let Some(input) = read_command() else { break };
+        if input.eq_ignore_ascii_case("PALETTE") {
+            switch_palette();
+            continue;
+        }
+        play_guess(&mut game, &input);
+    }
+    if game.status() == GameStatus::Won {
+        println!("{}", fill(messages.solved_in, &[&game.turns().len(), &game.max_attempts()]));
+        exit_code::SUCCESS
+    } else {
+        println!("{}", fill(messages.word_was, &[&secret]));
+        exit_code::LOST
+    }
+}
+
+// Plays one typed guess, drawing its tiles and the keyboard or saying why it was refused
+pub fn play_guess(game: &mut Game, input: &str) {
+    let guess = input.to_lowercase();
+    match game.guess(&guess) {
+        Ok(feedback) => {
+            style::reveal(&guess, feedback.as_str());
+            if game.status() == GameStatus::Ongoing {
+                println!("\n{}\n", style::keyboard(game.constraints()));
+            }
+        }
+        Err(e) => println!("{}", fill(messages::catalog().try_another, &[&e])),
+    }
+}
+
+// Plays a practice game against a secret drawn from the drill's pool. Practice games
+// don't count toward the stats, but solving a missed word takes it off the missed list
+pub fn play_practice(solver: &WordleSolver, drill: Drill, seed: Option<u64>, stats_file: Option<&str>) -> i32 {
+    let messages = messages::catalog();
+    let mut stats = match stats_file.map(PlayerStats::load).transpose() {
+        Ok(stats) => stats.unwrap_or_default(),
+        Err(e) => {
+            eprintln!("{}", e);
+            return exit_code::FAILURE;
+        }
+    };
+    let pool: Vec<String> = match drill {
+        Drill::Repeats => solver
+            .candidates()
+            .iter()
+            .filter(|word| word.chars().enumerate().any(|(idx, letter)| word[idx + 1..].contains(letter)))
+            .cloned()
+            .collect(),
+        Drill::Traps => solver.candidate_families().into_iter().flat_map(|family| family.words).collect(),
+        Drill::Missed => stats.missed.iter().filter(|word| solver.candidates().contains(word)).cloned().collect(),
+    };
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let Some(secret) = pool.choose(&mut rng) else {
+        match drill {
+            Drill::Missed => eprintln!("{}", messages.no_missed),
+            _ => eprintln!("{}", messages.no_drill_words),
+        }
+        return exit_code::DICTIONARY;
+    };
+
+    let mut game = Game::new(secret, solver.allowed_guesses().iter().cloned(), solver.max_attempts);
+    let (length, tries) = (game.word_length(), game.max_attempts());
+    println!("{}", fill(messages.practice_intro, &[&drill.label(), &pool.len(), &length, &tries]));
+    while game.status() == GameStatus::Ongoing {
+        println!("{}", fill(messages.guess_prompt, &[&(game.turns().len() + 1), &game.max_attempts()]));
+        let Some(input) = read_command() else { break };
+        if let Some(level) = hint_level(&input) {
+            give_hint(solver, &mut game, level, None);
+            continue;
+        }
+        if input.eq_ignore_ascii_case("GIVEUP") {
+            give_up(solver, &mut game);
+            break;
+        }
+        if input.eq_ignore_ascii_case("PALETTE") {
+            switch_palette();
+            continue;
+        }
+        play_guess(&mut game, &input);
+    }
+
+    if game.status() != GameStatus::Won {
+        if !game.given_up() {
+            println!("{}", fill(messages.word_was, &[&game.secret()]));
+        }
+        if game.status() == GameStatus::Lost {
+            print_solver_comparison(solver, &game);
+        }
+        return exit_code::LOST;
+    }
+    println!("{}", fill(messages.solved_in, &[&game.turns().len(), &game.max_attempts()]));
+    print_solver_comparison(solver, &game);
+    if let Some(path) = stats_file.filter(|_| stats.missed.iter().any(|word| word == secret)) {
+        stats.clear_missed(secret);
+        match stats.save(path) {
+            Ok(()) => println!("{}", fill(messages.off_missed_list, &[secret])),
+            Err(e) => eprintln!("{}", fill(messages.stats_update_failed, &[&e])),
+        }
+    }
+    exit_code::SUCCESS
+}
@@ -0,0 +1,399 @@
+//! Everything the solver says to the player, one catalog per language, picked once at
+//! startup. Placeholders are filled in order by `fill`; numbers that need a precision
+//! are formatted before they're filled in.
+
+use std::sync::OnceLock;
+use clap::ValueEnum;
+
+mod en;
+mod es;
+
+use en::ENGLISH;
+use es::SPANISH;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Language {
+    En,
+    Es,
+}
+
+impl Language {
+    pub fn catalog(self) -> &'static Catalog {
+        match self {
+            Language::En => &ENGLISH,
+            Language::Es => &SPANISH,
+        }
+    }
+}
+
+static CATALOG: OnceLock<&'static Catalog> = OnceLock::new();
+
+/// Picks the language of every message for the rest of the run.
+pub fn init(language: Language) {
+    let _ = CATALOG.set(language.catalog());
+}
+
+/// The catalog picked by `init`, English until then.
+pub fn catalog() -> &'static Catalog {
+    CATALOG.get().copied().unwrap_or(&ENGLISH)
+}
+
+/// `input` with a command typed in the picked catalog's language resolved to English,
+/// as [`Catalog::resolve_command`] does.
+pub fn resolve_command(input: &str) -> String {
+    catalog().resolve_command(input)
+}
+
+pub struct Catalog {
+    /// Command words in this language and the English keyword each stands for,
+    /// longest first so BOARD INFO's alias wins over BOARD's
+    pub keywords: &'static [(&'static str, &'static str)],
+
+    // The interactive solver
+    pub instructions: &'static str,
+    pub initial_guess: &'static str,
+    pub enter_results: &'static str,
+    pub next_guess: &'static str,
+    pub starting_over: &'static str,
+    pub starting_at: &'static str,
+    pub resumed_at: &'static str,
+    pub resume_failed: &'static str,
+    pub won: &'static str,
+    pub lost: &'static str,
+    pub invalid_results: &'static str,
+    pub invalid_turn: &'static str,
+    pub guess_usage: &'static str,
+    pub whatif_usage: &'static str,
+    pub cands_usage: &'static str,
+    pub nothing_to_undo: &'static str,
+    pub invalid_turn_number: &'static str,
+    pub saved: &'static str,
+    pub save_failed: &'static str,
+    pub transcript_written: &'static str,
+    pub transcript_failed: &'static str,
+    pub scoring_cancelled: &'static str,
+
+    // Setup
+    pub no_stats_dir: &'static str,
+    pub no_games_dir: &'static str,
+    pub log_file_failed: &'static str,
+    pub setup_failed: &'static str,
+    pub cancelling: &'static str,
+    pub ctrlc_failed: &'static str,
+    pub history_file_failed: &'static str,
+    #[cfg_attr(feature = "bundled-words", allow(dead_code))]
+    pub no_word_list: &'static str,
+    pub timing: &'static str,
+    pub timing_pass: &'static str,
+    pub timing_loading: &'static str,
+    pub timing_opener: &'static str,
+    pub timing_scoring: &'static str,
+    pub timing_applying: &'static str,
+
+    // wordlist
+    pub wrote_words: &'static str,
+    pub write_failed: &'static str,
+    pub validate_summary: &'static str,
+    pub top_openers: &'static str,
+    pub opener_row: &'static str,
+    pub list_summary: &'static str,
+    pub repeat_summary: &'static str,
+    pub repeat_row: &'static str,
+
+    // replay
+    pub replay_failed: &'static str,
+    pub replay_step: &'static str,
+    pub replay_recorded: &'static str,
+    pub replay_summary: &'static str,
+
+    // Histories and pasted turns
+    pub history_unreadable: &'static str,
+    pub history_no_fit: &'static str,
+    pub history_won: &'static str,
+    pub history_lost: &'static str,
+    pub history_invalid_turn: &'static str,
+    pub paste_unreadable: &'static str,
+    pub paste_rejected: &'static str,
+    pub paste_problem: &'static str,
+    pub paste_problem_turn: &'static str,
+    pub game_already_won: &'static str,
+    pub game_already_lost: &'static str,
+    pub no_word_fits: &'static str,
+    pub applied_turn: &'static str,
+    pub applied_turns: &'static str,
+    pub suggest_no_fit: &'static str,
+
+    // analyze
+    pub analyze_usage: &'static str,
+    pub analyze_over: &'static str,
+    pub analyze_solved: &'static str,
+    pub analyze_lost: &'static str,
+    pub analyze_unfinished: &'static str,
+    pub analyze_summary: &'static str,
+
+    // --verify, bench, simulate and compare
+    pub verifying: &'static str,
+    pub cancelled_after: &'static str,
+    pub verify_summary: &'static str,
+    pub bench_playing: &'static str,
+    pub average_guesses: &'static str,
+    pub failed_words: &'static str,
+    pub took: &'static str,
+    pub simulating: &'static str,
+    pub watch_game: &'static str,
+    pub watch_solved: &'static str,
+    pub watch_missed: &'static str,
+    pub won_in: &'static str,
+    pub missed_words: &'static str,
+    pub comparing: &'static str,
+
+    // Games: daily, practice, friend, endless, duel and multi
+    pub resume_game_failed: &'static str,
+    pub invalid_tz: &'static str,
+    pub invalid_date: &'static str,
+    pub no_candidates: &'static str,
+    pub too_few_candidates: &'static str,
+    pub daily_intro: &'static str,
+    pub mode_hard: &'static str,
+    pub mode_difficulty: &'static str,
+    pub mode_ranked: &'static str,
+    pub difficulty_easy: &'static str,
+    pub difficulty_hard: &'static str,
+    pub saved_game_mismatch: &'static str,
+    pub guess_prompt: &'static str,
+    pub guess_prompt_timed: &'static str,
+    pub try_another: &'static str,
+    pub word_was: &'static str,
+    pub words_were: &'static str,
+    pub daily_saved: &'static str,
+    pub daily_solved: &'static str,
+    pub daily_solved_timed: &'static str,
+    pub solved_in: &'static str,
+    pub out_of_guesses: &'static str,
+    pub out_of_guesses_word: &'static str,
+    pub personal_best: &'static str,
+    pub stats_update_failed: &'static str,
+    pub game_log_failed: &'static str,
+    pub practice_intro: &'static str,
+    pub drill_repeats: &'static str,
+    pub drill_traps: &'static str,
+    pub drill_missed: &'static str,
+    pub no_missed: &'static str,
+    pub no_drill_words: &'static str,
+    pub off_missed_list: &'static str,
+    pub friend_secret: &'static str,
+    pub not_a_word: &'static str,
+    pub not_in_list: &'static str,
+    pub friend_intro: &'static str,
+    pub picker_help: &'static str,
+    pub endless_intro: &'static str,
+    pub endless_word: &'static str,
+    pub endless_solved: &'static str,
+    pub endless_over_one: &'static str,
+    pub endless_over: &'static str,
+    pub endless_over_none: &'static str,
+    pub duel_intro: &'static str,
+    pub duel_prompt: &'static str,
+    pub duel_gave_up: &'static str,
+    pub duel_won: &'static str,
+    pub duel_lost: &'static str,
+    pub duel_draw: &'static str,
+    pub multi_intro: &'static str,
+    pub boards_solved: &'static str,
+    pub multi_won: &'static str,
+    pub solve_multi_intro: &'static str,
+    pub multi_no_fit: &'static str,
+    pub multi_suggestion: &'static str,
+    pub enter_board_results: &'static str,
+    pub enter_boards_results: &'static str,
+    pub multi_guess_usage: &'static str,
+    pub multi_result_count: &'static str,
+    pub board_solved: &'static str,
+    pub board_left: &'static str,
+    pub multi_solved: &'static str,
+    pub palette_now: &'static str,
+    pub palette_standard: &'static str,
+    pub palette_color_blind: &'static str,
+
+    // Hints, giving up and the solver's replay of a finished game
+    pub hints_off: &'static str,
+    pub hints_used_up: &'static str,
+    pub hint_word_left: &'static str,
+    pub hint_words_left: &'static str,
+    pub hint_letter: &'static str,
+    pub hint_all_found: &'static str,
+    pub hint_lost_track: &'static str,
+    pub hint_try: &'static str,
+    pub hint_levels: &'static str,
+    pub hint_budget: &'static str,
+    pub still_fit: &'static str,
+    pub and_more: &'static str,
+    pub no_shortfall: &'static str,
+    pub shortfall: &'static str,
+    pub solver_from_your_opener: &'static str,
+    pub solver_own_opener: &'static str,
+    pub against_yours: &'static str,
+
+    // review and stats
+    pub no_games: &'static str,
+    pub review_usage: &'static str,
+    pub no_such_game_one: &'static str,
+    pub no_such_game: &'static str,
+    pub review_lost_track: &'static str,
+    pub review_intro: &'static str,
+    pub review_turn: &'static str,
+    pub review_left: &'static str,
+    pub review_solved: &'static str,
+    pub review_best: &'static str,
+    pub review_as_good: &'static str,
+    pub stats_summary: &'static str,
+    pub stats_hints: &'static str,
+    pub stats_best_time: &'static str,
+
+    // search and profile
+    pub search_pattern: &'static str,
+    pub search_not_letter: &'static str,
+    pub search_clash: &'static str,
+    pub search_count: &'static str,
+    pub profiling: &'static str,
+    pub phase_picking: &'static str,
+    pub phase_opener: &'static str,
+    pub word_list_size: &'static str,
+
+    // Commands of the interactive solver
+    pub status_attempt: &'static str,
+    pub status_candidates: &'static str,
+    pub status_uncertainty: &'static str,
+    pub status_weighted_uncertainty: &'static str,
+    pub status_strategy: &'static str,
+    pub status_expected: &'static str,
+    pub status_win_chance: &'static str,
+    pub kind_answer: &'static str,
+    pub kind_probe: &'static str,
+    pub kept_turn: &'static str,
+    pub game_would_be_over: &'static str,
+    pub removed_turn: &'static str,
+    pub removed_replayed_one: &'static str,
+    pub removed_replayed: &'static str,
+    pub answer_must_be: &'static str,
+    pub two_left: &'static str,
+    pub tied_guesses: &'static str,
+    pub pick_tie: &'static str,
+    pub invalid_choice: &'static str,
+    pub picked_by: &'static str,
+    pub explain_information: &'static str,
+    pub explain_split: &'static str,
+    pub explain_worst: &'static str,
+    pub explain_answer: &'static str,
+    pub explain_probe: &'static str,
+    pub explain_runner_up: &'static str,
+    pub partition_header: &'static str,
+    pub expected_information: &'static str,
+    pub worst_case: &'static str,
+    pub no_guesses: &'static str,
+    pub diff_header: &'static str,
+    pub diff_buckets: &'static str,
+    pub no_families: &'static str,
+    pub family: &'static str,
+    pub family_warning: &'static str,
+    pub family_probe: &'static str,
+    pub family_probe_none: &'static str,
+    pub all_tried: &'static str,
+    pub untested: &'static str,
+    pub invalid_regex: &'static str,
+    pub filter_usage: &'static str,
+    pub filter_header: &'static str,
+    pub no_candidates_left: &'static str,
+    pub cands_header: &'static str,
+    pub cands_next: &'static str,
+    pub nothing_known: &'static str,
+    pub known_greens: &'static str,
+    pub known_yellows: &'static str,
+    pub yellow_not: &'static str,
+    pub known_excluded: &'static str,
+    pub known_counts: &'static str,
+    pub count_exactly: &'static str,
+    pub count_at_least: &'static str,
+
+    // Table headings
+    pub heading_guess: &'static str,
+    pub heading_result: &'static str,
+    pub heading_left: &'static str,
+    pub heading_bits: &'static str,
+    pub heading_best: &'static str,
+    pub heading_grade: &'static str,
+    pub heading_word: &'static str,
+    pub heading_entropy: &'static str,
+    pub heading_worst_case: &'static str,
+    pub heading_kind: &'static str,
+    pub heading_expected: &'static str,
+    pub heading_actual: &'static str,
+    pub heading_before: &'static str,
+    pub heading_after: &'static str,
+    pub heading_letter: &'static str,
+    pub heading_total: &'static str,
+    pub heading_phase: &'static str,
+    pub heading_min_ms: &'static str,
+    pub heading_avg_ms: &'static str,
+    pub heading_max_ms: &'static str,
+    pub heading_strategy: &'static str,
+    pub heading_average: &'static str,
+    pub heading_time: &'static str,
+    pub heading_you: &'static str,
+    pub heading_solver: &'static str,
+}
+
+impl Catalog {
+    /// `input` with a command typed in this catalog's language, like ESTADO, swapped
+    /// for the English keyword it stands for and the rest of the line kept, so the
+    /// commands only have to be matched in English. English keywords work in every
+    /// language.
+    pub fn resolve_command(&self, input: &str) -> String {
+        for &(alias, command) in self.keywords {
+            let Some(typed) = input.get(..alias.len()) else { continue };
+            let rest = &input[alias.len()..];
+            if typed.eq_ignore_ascii_case(alias) && (rest.is_empty() || rest.starts_with(char::is_whitespace)) {
+                return format!("{}{}", command, rest);
+            }
+        }
+        input.to_string()
+    }
+}
+
+/// Replaces each `{}` in `template` with the next of `values`.
+pub fn fill(template: &str, values: &[&dyn std::fmt::Display]) -> String {
+    let mut values = values.iter();
+    let mut pieces = template.split("{}");
+    let mut filled = pieces.next().unwrap_or_default().to_string();
+    for piece in pieces {
+        if let Some(value) = values.next() {
+            filled.push_str(&value.to_string());
+        }
+        filled.push_str(piece);
+    }
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spanish_commands_resolve_to_english() {
+        assert_eq!(SPANISH.resolve_command("deshacer 2"), "UNDO 2");
+        assert_eq!(SPANISH.resolve_command("CANDIDATOS SIGUIENTE"), "CANDS NEXT");
+        assert_eq!(SPANISH.resolve_command("candidatos 3"), "CANDS 3");
+        // English keywords pass through, and a longer word isn't mistaken for a command
+        assert_eq!(SPANISH.resolve_command("UNDO"), "UNDO");
+        assert_eq!(SPANISH.resolve_command("DESHACERLO"), "DESHACERLO");
+        assert_eq!(ENGLISH.resolve_command("deshacer"), "deshacer");
+    }
+
+    #[test]
+    fn fill_replaces_placeholders_in_order() {
+        assert_eq!(fill("{} of {} left", &[&3, &"ten"]), "3 of ten left");
+        // Missing values leave the placeholder empty, and extra values are dropped
+        assert_eq!(fill("{} and {}", &[&1]), "1 and ");
+        assert_eq!(fill("none", &[&1]), "none");
+    }
+}
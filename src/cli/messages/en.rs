@@ -0,0 +1,315 @@
+//! The English catalog, also the fallback before a language is picked.
+
+use super::Catalog;
+
+pub const ENGLISH: Catalog = Catalog {
+    keywords: &[],
+
+    instructions: "Welcome to Drew's Wordle solver! Here's how to use it:\n\
+        * Type in the results of each guess as a string of 'G', 'Y', and 'B'.\n\
+        * 'G' for Green (correct position)\n\
+        * 'Y' for Yellow (wrong position)\n\
+        * 'B' for Black (not in the word)\n\
+        * Or paste the row of colored squares from a share grid, or type digits: 2 green, 1 yellow, 0 black\n\
+        * Press enter to submit the results to the solver\n\
+        * Type 'GUESS <word> <results>' if you played a different word than the one suggested\n\
+        * Paste turns so far like 'crane=BYBBG; slimy=BBGYB', or one per line\n\
+        * Type 'STATUS' to see how the game is going\n\
+        * Type 'TOP' to see the best-scoring guesses\n\
+        * Type 'EXPLAIN' to see why the solver picked its guess\n\
+        * Type 'WHATIF <word>' to see how a guess would split the candidates\n\
+        * Type 'FAMILIES' to find groups of candidates differing in one letter\n\
+        * Type 'BOARD' to review your guesses so far, or 'BOARD INFO' to add information in bits\n\
+        * Type 'PALETTE' to switch the board to color-blind friendly colors and back\n\
+        * Type 'KEYS' to see the alphabet colored by what you know of each letter\n\
+        * Type 'CANDS' to list the remaining candidates a page at a time, then 'CANDS NEXT' or 'CANDS <page>'\n\
+        * Type 'FILTER <pattern>' to list the candidates matching s___t, or a regex like re:^s.*t$\n\
+        * Type 'KNOWN' to see what the feedback so far says about the answer\n\
+        * Type 'DIFF' to see which candidates your last results ruled out, and why\n\
+        * Type 'UNDO' to take back your last results, or 'UNDO <n>' to remove turn n and replay the ones after it\n\
+        * Type 'RESTART' to start over with the same word list\n\
+        * Type 'SAVE <file>' to save the game, and 'RESUME <file>' to pick a saved game back up\n\
+        * Type 'TRANSCRIPT <file>' to write out every turn and suggestion, e.g. for a bug report\n\
+        * Type 'EXIT' to quit the game",
+    initial_guess: "The solver's initial guess is: {} ({})",
+    enter_results: "Enter results for '{}': ",
+    next_guess: "Next guess: {} ({})",
+    starting_over: "Starting over. The solver's initial guess is: {}",
+    starting_at: "Starting at turn {}. Next guess: {} ({})",
+    resumed_at: "Resumed at turn {}. Next guess: {} ({})",
+    resume_failed: "Failed to resume session: {}",
+    won: "Congratulations, you won!",
+    lost: "Game over. Better luck next time!",
+    invalid_results: "Invalid results: {}.",
+    invalid_turn: "Invalid turn: {}.",
+    guess_usage: "Give the word you played and its results, like GUESS stare GYBBY.",
+    whatif_usage: "Invalid word. Please enter a {}-letter word after WHATIF.",
+    cands_usage: "Use CANDS, CANDS NEXT or CANDS <page>.",
+    nothing_to_undo: "Nothing to undo yet.",
+    invalid_turn_number: "Invalid turn. Please enter a turn from 1 to {}.",
+    saved: "Saved the game to {}.",
+    save_failed: "Failed to save the game: {}",
+    transcript_written: "Wrote the transcript to {}.",
+    transcript_failed: "Failed to write the transcript: {}",
+    scoring_cancelled: "Scoring was cancelled, so this is the best guess found before it stopped.",
+
+    no_stats_dir: "No data directory to keep stats in; give one with --stats-file.",
+    no_games_dir: "No data directory to keep games in; give one with --stats-file.",
+    log_file_failed: "Failed to open the log file: {}",
+    setup_failed: "Failed to set up the solver: {}",
+    cancelling: "\nCancelling. Press Ctrl-C again to quit.",
+    ctrlc_failed: "Couldn't install the Ctrl-C handler: {}",
+    history_file_failed: "Failed to read history file {}: {}",
+    no_word_list: "No word list given. Pass a path or set WORDLE_SOLVER_WORDLIST.",
+    timing: "[timing] {} took {} ms",
+    timing_pass: "[timing]   {} candidates left, {} strategy in charge",
+    timing_loading: "loading the word lists",
+    timing_opener: "picking the opener",
+    timing_scoring: "scoring every guess",
+    timing_applying: "applying the results and picking the next guess",
+
+    wrote_words: "Wrote {} words to {}.",
+    write_failed: "Failed to write {}: {}",
+    validate_summary: "{} usable words, {} problem lines.",
+    top_openers: "Top openers:",
+    opener_row: "  {}\t{} bits\tworst case {}",
+    list_summary: "{} words of {} letters",
+    repeat_summary: "{} words ({}%) repeat a letter",
+    repeat_row: "  {} with a letter {} times",
+
+    replay_failed: "Failed to replay transcript: {}",
+    replay_step: "  {}  played {}, solver suggests {}",
+    replay_recorded: "  (transcript recorded {})",
+    replay_summary: "Replayed {} turns, {} with a different suggestion.",
+
+    history_unreadable: "Couldn't read the history '{}'; expected pairs like crane=BYBBG,slimy=BBGYB.",
+    history_no_fit: "No word in the list fits the history up to {}={}.",
+    history_won: "The game was already won with {}.",
+    history_lost: "The game was already lost after {}.",
+    history_invalid_turn: "Invalid turn {}={}: {}.",
+    paste_unreadable: "Couldn't read the turns; paste pairs like crane=BYBBG; slimy=BBGYB.",
+    paste_rejected: "None of the {} turns were applied:\n{}",
+    paste_problem: "  turn {}: {}",
+    paste_problem_turn: "  turn {} ({}={}): {}",
+    game_already_won: "the game was already won",
+    game_already_lost: "the game was already lost",
+    no_word_fits: "no word in the list fits",
+    applied_turn: "Applied 1 turn; {} candidates left.",
+    applied_turns: "Applied {} turns; {} candidates left.",
+    suggest_no_fit: "No word in the list fits that history.",
+
+    analyze_usage: "Give the game to analyze with --history, e.g. --history crane=BYBBG,slimy=BBGYB.",
+    analyze_over: "The game was already over before {}.",
+    analyze_solved: "Solved in {}",
+    analyze_lost: "Lost",
+    analyze_unfinished: "Unfinished after {}",
+    analyze_summary: "{}. Overall grade {}: {}% of the best information available.",
+
+    verifying: "Verifying {} answers...",
+    cancelled_after: "Cancelled after {} of {} answers.",
+    verify_summary: "Solved {} of {} within {} guesses.",
+    bench_playing: "Playing {} answers with the {} strategy...",
+    average_guesses: "Average guesses: {} over {} games",
+    failed_words: "Failed: {}",
+    took: "Took {}s",
+    simulating: "Playing {} random games...",
+    watch_game: "Game {} of {}:",
+    watch_solved: "  Solved in {}\n",
+    watch_missed: "  Missed in {}\n",
+    won_in: "Won in {}; {} missed",
+    missed_words: "Missed: {}",
+    comparing: "Playing {} answers with each strategy...",
+
+    resume_game_failed: "Failed to resume the game: {}",
+    invalid_tz: "Invalid time zone offset '{}'. Use a form like +05:30, -0800 or UTC.",
+    invalid_date: "Invalid date. Use the form YYYY-MM-DD.",
+    no_candidates: "The word list has no candidates.",
+    too_few_candidates: "The word list has fewer than {} candidates.",
+    daily_intro: "Daily puzzle #{} ({}{}). Guess the {}-letter word in {} tries, type HINT (or HINT 2, HINT 3 for \
+        more) for help, SHARE to see the share grid, SAVE <file> to continue later, GIVEUP to see the \
+        answer and what went wrong, or EXIT to stop.",
+    mode_hard: ", hard mode",
+    mode_difficulty: ", {} difficulty",
+    mode_ranked: ", ranked",
+    difficulty_easy: "easy",
+    difficulty_hard: "hard",
+    saved_game_mismatch: "The saved game doesn't fit this word list: {}",
+    guess_prompt: "Guess {} of {}: ",
+    guess_prompt_timed: "Guess {} of {} [{}]: ",
+    try_another: "{}. Try another word.",
+    word_was: "The word was {}.",
+    words_were: "The words were {}.",
+    daily_saved: "Saved the game to {}. Pick it up with daily --resume {}.",
+    daily_solved: "Solved puzzle #{} in {}/{}!",
+    daily_solved_timed: "Solved puzzle #{} in {}/{}, in {}!",
+    solved_in: "Solved in {}/{}!",
+    out_of_guesses: "Out of guesses.",
+    out_of_guesses_word: "Out of guesses. The word was {}.",
+    personal_best: "That's a new personal best!",
+    stats_update_failed: "Couldn't update the stats: {}",
+    game_log_failed: "Couldn't log the game for review: {}",
+    practice_intro: "Practice: {} ({} in the pool). Guess the {}-letter word in {} tries, type HINT (or HINT 2, \
+        HINT 3 for more) for help, GIVEUP to see the answer and what went wrong, or EXIT to stop.",
+    drill_repeats: "repeated letters",
+    drill_traps: "one-letter traps",
+    drill_missed: "missed words",
+    no_missed: "No missed words to practice; lost daily games are added here.",
+    no_drill_words: "No word in the list fits that drill.",
+    off_missed_list: "{} is off your missed list.",
+    friend_secret: "Player one: type a {}-letter word for your friend to guess (it won't be shown).",
+    not_a_word: "That isn't a {}-letter word.",
+    not_in_list: "That word isn't in the word list, so it couldn't be guessed.",
+    friend_intro: "Player two: guess your friend's {}-letter word in {} tries, or type EXIT to give up.",
+    picker_help: "Color each tile: ←/→ to move, G/Y/B or space to color, Enter to submit, Esc to type instead.",
+    endless_intro: "Endless: solve word after word in {} tries each. One miss ends the run; EXIT stops it.",
+    endless_word: "\nWord {} (streak {}):",
+    endless_solved: "Solved in {}/{}! Streak: {}",
+    endless_over_one: "Run over: 1 word solved, {} guesses each on average.",
+    endless_over: "Run over: {} words solved, {} guesses each on average.",
+    endless_over_none: "Run over before the first word was solved.",
+    duel_intro: "Duel: you and the solver each get {} tries at the same {}-letter word; you go first. Type EXIT to give up.",
+    duel_prompt: "Your guess {} of {}: ",
+    duel_gave_up: "You gave up. The word was {}.",
+    duel_won: "You got {} in {}, before the solver. You win!",
+    duel_lost: "The solver got it in {} first. The word was {}.",
+    duel_draw: "Neither of you found it. The word was {}.",
+    multi_intro: "{} boards: every guess counts on each board not yet solved. Solve them all in {} tries, or type EXIT to give up.",
+    boards_solved: "  {} of {} solved",
+    multi_won: "Solved all {} boards in {}/{}!",
+    solve_multi_intro: "{} boards in {} tries. After each guess, enter its results on every unsolved board in order, separated \
+        by spaces. Type GUESS <word> <results...> if you played another word, or EXIT to stop.",
+    multi_no_fit: "No word fits the results on every board.",
+    multi_suggestion: "Guess {} of {}: {}",
+    enter_board_results: "Enter results for board {}: ",
+    enter_boards_results: "Enter results for boards {}: ",
+    multi_guess_usage: "Give the word you played and its results, like GUESS stare GYBBY BBYGB.",
+    multi_result_count: "Give one result for each of the {} unsolved boards.",
+    board_solved: "  board {}: solved",
+    board_left: "  board {}: {} left",
+    multi_solved: "All {} boards solved in {}/{}!",
+    palette_now: "Now using the {} palette.",
+    palette_standard: "standard",
+    palette_color_blind: "color-blind",
+
+    hints_off: "Hints are off in this game.",
+    hints_used_up: "No hints left; this game allows {}.",
+    hint_word_left: "1 possible word left.",
+    hint_words_left: "{} possible words left.",
+    hint_letter: "The word contains {}.",
+    hint_all_found: "You've found every letter already; try HINT 1 or HINT 3.",
+    hint_lost_track: "The solver can't follow this game, so there's no hint.",
+    hint_try: "Try {}.",
+    hint_levels: "Hint levels are 1, 2 and 3.",
+    hint_budget: "{} ({} of {} hints used)",
+    still_fit: "{} words still fit your clues: {}{}",
+    and_more: " and {} more",
+    no_shortfall: "Every guess was as informative as the best available.",
+    shortfall: "  {} gave {} bits; {} would have given {}",
+    solver_from_your_opener: "\nThe solver from your opener:",
+    solver_own_opener: "\nThe solver with its own opener:",
+    against_yours: "  {}/{} against your {}/{}",
+
+    no_games: "No finished daily games yet.",
+    review_usage: "Step through one with review <number>.",
+    no_such_game_one: "There is no game {}; 1 is logged.",
+    no_such_game: "There is no game {}; {} are logged.",
+    review_lost_track: "The solver can't follow this game past {}: {}.",
+    review_intro: "{}: {}/{}. Press Enter for each turn, or Q to stop.",
+    review_turn: "Turn {}:",
+    review_left: "  {} left {} of {} words ({} bits).",
+    review_solved: "  Solved.",
+    review_best: "  The solver's pick was {}, worth {} bits.",
+    review_as_good: "  As informative as the best guess available.",
+    stats_summary: "Played {}, won {}%, current streak {}, max streak {}",
+    stats_hints: "Hints taken: {}",
+    stats_best_time: "Fastest timed win: {}",
+
+    search_pattern: "The pattern must be {} letters or wildcards (_ or ?), e.g. _a_le.",
+    search_not_letter: "'{}' is not a letter; --include and --exclude take runs of letters like sr.",
+    search_clash: "'{}' can't be both excluded and required.",
+    search_count: "{} matching words.",
+    profiling: "Profiling {} candidates and {} allowed guesses, {} runs each...",
+    phase_picking: "picking a guess",
+    phase_opener: "opener",
+    word_list_size: "Word lists take about {} KiB.",
+
+    status_attempt: "Attempt {} of {}",
+    status_candidates: "Remaining candidates: {}",
+    status_uncertainty: "Remaining uncertainty: {} bits",
+    status_weighted_uncertainty: "Remaining uncertainty: {} bits ({} bits ignoring word weights)",
+    status_strategy: "Strategy: {}",
+    status_expected: "Expected guesses to finish: {}",
+    status_win_chance: "Chance to win within the remaining {} guesses: {}%",
+    kind_answer: "possible answer",
+    kind_probe: "probe (cannot be the answer)",
+    kept_turn: "Kept turn {}: without it, {}={} doesn't fit ({}).",
+    game_would_be_over: "the game would already be over",
+    removed_turn: "Removed turn {}.",
+    removed_replayed_one: "Removed turn {} and replayed the one after it; {} candidates left.",
+    removed_replayed: "Removed turn {} and replayed the {} after it; {} candidates left.",
+    answer_must_be: "The answer must be {}.",
+    two_left: "Only {} and {} are left; guess either.",
+    tied_guesses: "{} guesses are tied for best:",
+    pick_tie: "Pick a guess by number (press enter for '{}'): ",
+    invalid_choice: "Invalid choice. Please enter a number from 1 to {}.",
+    picked_by: "'{}' was picked by the {} strategy.",
+    explain_information: "  Expected information: {} bits",
+    explain_split: "  Splits {} candidates into {} feedback patterns",
+    explain_worst: "  Worst case leaves {} candidates",
+    explain_answer: "  It could be the answer itself",
+    explain_probe: "  It cannot be the answer; it is a probe to narrow things down",
+    explain_runner_up: "  Next best by entropy: '{}' with {} bits (worst case {})",
+    partition_header: "'{}' splits {} candidates into {} patterns:",
+    expected_information: "Expected information: {} bits",
+    worst_case: "Worst case: {} candidates",
+    no_guesses: "No guesses yet.",
+    diff_header: "Turn {} ({} {}) ruled out {} of {} candidates.",
+    diff_buckets: "Each would have shown different feedback:",
+    no_families: "No groups of {} or more candidates differ in just one letter.",
+    family: "{} family ({}): {}",
+    family_warning: "  Warning: only {} guesses left, so guessing these one at a time could run out of turns",
+    family_probe: "  Probe: '{}' tests {} and splits the family into {} groups",
+    family_probe_none: "none of the differing letters",
+    all_tried: "Every letter has been tried.",
+    untested: "Untested: {}",
+    invalid_regex: "Invalid regex: {}",
+    filter_usage: "Give {} letters or wildcards (_ or ?) like s___t, or a regex like re:^s.*t$.",
+    filter_header: "{} of {} candidates match:",
+    no_candidates_left: "No candidates left.",
+    cands_header: "{} remaining candidates, {} to {} (page {} of {}):",
+    cands_next: "CANDS NEXT for the next page.",
+    nothing_known: "Nothing known yet.",
+    known_greens: "Greens:   {}",
+    known_yellows: "Yellows:  {}",
+    yellow_not: "{} (not {})",
+    known_excluded: "Excluded: {}",
+    known_counts: "Counts:   {}",
+    count_exactly: "{} exactly {}",
+    count_at_least: "{} at least {}",
+
+    heading_guess: "guess",
+    heading_result: "result",
+    heading_left: "left",
+    heading_bits: "bits",
+    heading_best: "best",
+    heading_grade: "grade",
+    heading_word: "word",
+    heading_entropy: "entropy",
+    heading_worst_case: "worst case",
+    heading_kind: "kind",
+    heading_expected: "expected",
+    heading_actual: "actual",
+    heading_before: "before",
+    heading_after: "after",
+    heading_letter: "letter",
+    heading_total: "total",
+    heading_phase: "phase",
+    heading_min_ms: "min ms",
+    heading_avg_ms: "avg ms",
+    heading_max_ms: "max ms",
+    heading_strategy: "strategy",
+    heading_average: "average",
+    heading_time: "time",
+    heading_you: "you",
+    heading_solver: "solver",
+};
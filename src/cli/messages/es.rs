@@ -0,0 +1,344 @@
+//! The Spanish catalog.
+
+use super::Catalog;
+
+pub const SPANISH: Catalog = Catalog {
+    keywords: &[
+        ("CANDIDATOS SIGUIENTE", "CANDS NEXT"),
+        ("TABLERO INFO", "BOARD INFO"),
+        ("TRANSCRIPCION", "TRANSCRIPT"),
+        ("CANDIDATOS", "CANDS"),
+        ("DIFERENCIA", "DIFF"),
+        ("REINICIAR", "RESTART"),
+        ("COMPARTIR", "SHARE"),
+        ("FAMILIAS", "FAMILIES"),
+        ("DESHACER", "UNDO"),
+        ("EXPLICAR", "EXPLAIN"),
+        ("REANUDAR", "RESUME"),
+        ("RENDIRSE", "GIVEUP"),
+        ("CONOCIDO", "KNOWN"),
+        ("FILTRAR", "FILTER"),
+        ("GUARDAR", "SAVE"),
+        ("MEJORES", "TOP"),
+        ("SUPONER", "WHATIF"),
+        ("TABLERO", "BOARD"),
+        ("INTENTO", "GUESS"),
+        ("ESTADO", "STATUS"),
+        ("TECLAS", "KEYS"),
+        ("PALETA", "PALETTE"),
+        ("PISTA", "HINT"),
+        ("SALIR", "EXIT"),
+    ],
+
+    instructions: "¡Bienvenido al solucionador de Wordle de Drew! Así se usa:\n\
+        * Escribe el resultado de cada intento como una cadena de 'G', 'Y' y 'B'.\n\
+        * 'G' para verde (posición correcta)\n\
+        * 'Y' para amarillo (posición incorrecta)\n\
+        * 'B' para negro (no está en la palabra)\n\
+        * O pega la fila de cuadrados de colores de una cuadrícula compartida, o escribe dígitos: 2 verde, 1 amarillo, 0 negro\n\
+        * Pulsa Intro para enviar el resultado al solucionador\n\
+        * Escribe 'INTENTO <palabra> <resultado>' si jugaste otra palabra distinta de la sugerida\n\
+        * Pega los turnos jugados como 'crane=BYBBG; slimy=BBGYB', o uno por línea\n\
+        * Escribe 'ESTADO' para ver cómo va la partida\n\
+        * Escribe 'MEJORES' para ver los intentos con mejor puntuación\n\
+        * Escribe 'EXPLICAR' para ver por qué el solucionador eligió su intento\n\
+        * Escribe 'SUPONER <palabra>' para ver cómo dividiría un intento a los candidatos\n\
+        * Escribe 'FAMILIAS' para encontrar grupos de candidatos que difieren en una letra\n\
+        * Escribe 'TABLERO' para repasar tus intentos, o 'TABLERO INFO' para añadir la información en bits\n\
+        * Escribe 'PALETA' para cambiar el tablero a colores aptos para daltónicos y viceversa\n\
+        * Escribe 'TECLAS' para ver el alfabeto coloreado según lo que sabes de cada letra\n\
+        * Escribe 'CANDIDATOS' para listar los candidatos que quedan por páginas, y luego 'CANDIDATOS SIGUIENTE' o 'CANDIDATOS <página>'\n\
+        * Escribe 'FILTRAR <patrón>' para listar los candidatos que encajan con s___t, o con una regex como re:^s.*t$\n\
+        * Escribe 'CONOCIDO' para ver lo que dicen los resultados hasta ahora sobre la respuesta\n\
+        * Escribe 'DIFERENCIA' para ver qué candidatos descartó tu último resultado, y por qué\n\
+        * Escribe 'DESHACER' para deshacer tu último resultado, o 'DESHACER <n>' para quitar el turno n y volver a aplicar los siguientes\n\
+        * Escribe 'REINICIAR' para empezar de nuevo con la misma lista de palabras\n\
+        * Escribe 'GUARDAR <archivo>' para guardar la partida, y 'REANUDAR <archivo>' para retomarla\n\
+        * Escribe 'TRANSCRIPCION <archivo>' para escribir cada turno y sugerencia, p. ej. para un informe de error\n\
+        * Escribe 'SALIR' para salir\n\
+        * Las órdenes en inglés, como STATUS o UNDO, también valen",
+    initial_guess: "El primer intento del solucionador es: {} ({})",
+    enter_results: "Introduce el resultado de '{}': ",
+    next_guess: "Siguiente intento: {} ({})",
+    starting_over: "Empezando de nuevo. El primer intento del solucionador es: {}",
+    starting_at: "Empezando en el turno {}. Siguiente intento: {} ({})",
+    resumed_at: "Retomada en el turno {}. Siguiente intento: {} ({})",
+    resume_failed: "No se pudo retomar la sesión: {}",
+    won: "¡Enhorabuena, has ganado!",
+    lost: "Fin de la partida. ¡Más suerte la próxima vez!",
+    invalid_results: "Resultado no válido: {}.",
+    invalid_turn: "Turno no válido: {}.",
+    guess_usage: "Indica la palabra que jugaste y su resultado, como INTENTO stare GYBBY.",
+    whatif_usage: "Palabra no válida. Escribe una palabra de {} letras después de SUPONER.",
+    cands_usage: "Usa CANDIDATOS, CANDIDATOS SIGUIENTE o CANDIDATOS <página>.",
+    nothing_to_undo: "Todavía no hay nada que deshacer.",
+    invalid_turn_number: "Turno no válido. Escribe un turno del 1 al {}.",
+    saved: "Partida guardada en {}.",
+    save_failed: "No se pudo guardar la partida: {}",
+    transcript_written: "Transcripción escrita en {}.",
+    transcript_failed: "No se pudo escribir la transcripción: {}",
+    scoring_cancelled: "Se canceló la puntuación, así que este es el mejor intento encontrado antes de parar.",
+
+    no_stats_dir: "No hay carpeta de datos donde guardar las estadísticas; indica una con --stats-file.",
+    no_games_dir: "No hay carpeta de datos donde guardar las partidas; indica una con --stats-file.",
+    log_file_failed: "No se pudo abrir el archivo de registro: {}",
+    setup_failed: "No se pudo preparar el solucionador: {}",
+    cancelling: "\nCancelando. Pulsa Ctrl-C otra vez para salir.",
+    ctrlc_failed: "No se pudo instalar el manejador de Ctrl-C: {}",
+    history_file_failed: "No se pudo leer el archivo de historial {}: {}",
+    no_word_list: "No se indicó ninguna lista de palabras. Pasa una ruta o define WORDLE_SOLVER_WORDLIST.",
+    timing: "[tiempo] {} tardó {} ms",
+    timing_pass: "[tiempo]   quedan {} candidatos, al mando la estrategia {}",
+    timing_loading: "cargar las listas de palabras",
+    timing_opener: "elegir la apertura",
+    timing_scoring: "puntuar todos los intentos",
+    timing_applying: "aplicar el resultado y elegir el siguiente intento",
+
+    wrote_words: "Escritas {} palabras en {}.",
+    write_failed: "No se pudo escribir {}: {}",
+    validate_summary: "{} palabras utilizables, {} líneas con problemas.",
+    top_openers: "Mejores aperturas:",
+    opener_row: "  {}\t{} bits\tpeor caso {}",
+    list_summary: "{} palabras de {} letras",
+    repeat_summary: "{} palabras ({}%) repiten una letra",
+    repeat_row: "  {} con una letra {} veces",
+
+    replay_failed: "No se pudo reproducir la transcripción: {}",
+    replay_step: "  {}  jugado {}, el solucionador sugiere {}",
+    replay_recorded: "  (la transcripción registró {})",
+    replay_summary: "Reproducidos {} turnos, {} con una sugerencia distinta.",
+
+    history_unreadable: "No se pudo leer el historial '{}'; se esperaban pares como crane=BYBBG,slimy=BBGYB.",
+    history_no_fit: "Ninguna palabra de la lista encaja con el historial hasta {}={}.",
+    history_won: "La partida ya se había ganado con {}.",
+    history_lost: "La partida ya se había perdido tras {}.",
+    history_invalid_turn: "Turno no válido {}={}: {}.",
+    paste_unreadable: "No se pudieron leer los turnos; pega pares como crane=BYBBG; slimy=BBGYB.",
+    paste_rejected: "No se aplicó ninguno de los {} turnos:\n{}",
+    paste_problem: "  turno {}: {}",
+    paste_problem_turn: "  turno {} ({}={}): {}",
+    game_already_won: "la partida ya estaba ganada",
+    game_already_lost: "la partida ya estaba perdida",
+    no_word_fits: "ninguna palabra de la lista encaja",
+    applied_turn: "Aplicado 1 turno; quedan {} candidatos.",
+    applied_turns: "Aplicados {} turnos; quedan {} candidatos.",
+    suggest_no_fit: "Ninguna palabra de la lista encaja con ese historial.",
+
+    analyze_usage: "Indica la partida a analizar con --history, p. ej. --history crane=BYBBG,slimy=BBGYB.",
+    analyze_over: "La partida ya había terminado antes de {}.",
+    analyze_solved: "Resuelta en {}",
+    analyze_lost: "Perdida",
+    analyze_unfinished: "Sin terminar tras {}",
+    analyze_summary: "{}. Nota global {}: {}% de la mejor información disponible.",
+
+    verifying: "Verificando {} respuestas...",
+    cancelled_after: "Cancelado tras {} de {} respuestas.",
+    verify_summary: "Resueltas {} de {} en {} intentos como mucho.",
+    bench_playing: "Jugando {} respuestas con la estrategia {}...",
+    average_guesses: "Media de intentos: {} en {} partidas",
+    failed_words: "Fallidas: {}",
+    took: "Tardó {} s",
+    simulating: "Jugando {} partidas al azar...",
+    watch_game: "Partida {} de {}:",
+    watch_solved: "  Resuelta en {}\n",
+    watch_missed: "  Fallada en {}\n",
+    won_in: "Ganadas en {}; {} falladas",
+    missed_words: "Falladas: {}",
+    comparing: "Jugando {} respuestas con cada estrategia...",
+
+    resume_game_failed: "No se pudo retomar la partida: {}",
+    invalid_tz: "Desfase horario '{}' no válido. Usa una forma como +05:30, -0800 o UTC.",
+    invalid_date: "Fecha no válida. Usa la forma AAAA-MM-DD.",
+    no_candidates: "La lista de palabras no tiene candidatos.",
+    too_few_candidates: "La lista de palabras tiene menos de {} candidatos.",
+    daily_intro: "Reto diario n.º {} ({}{}). Adivina la palabra de {} letras en {} intentos; escribe PISTA (o PISTA 2, \
+        PISTA 3 para más) para pedir ayuda, COMPARTIR para ver la cuadrícula, GUARDAR <archivo> para seguir más \
+        tarde, RENDIRSE para ver la respuesta y qué falló, o SALIR para parar.",
+    mode_hard: ", modo difícil",
+    mode_difficulty: ", dificultad {}",
+    mode_ranked: ", clasificatoria",
+    difficulty_easy: "fácil",
+    difficulty_hard: "difícil",
+    saved_game_mismatch: "La partida guardada no encaja con esta lista de palabras: {}",
+    guess_prompt: "Intento {} de {}: ",
+    guess_prompt_timed: "Intento {} de {} [{}]: ",
+    try_another: "{}. Prueba otra palabra.",
+    word_was: "La palabra era {}.",
+    words_were: "Las palabras eran {}.",
+    daily_saved: "Partida guardada en {}. Retómala con daily --resume {}.",
+    daily_solved: "¡Reto n.º {} resuelto en {}/{}!",
+    daily_solved_timed: "¡Reto n.º {} resuelto en {}/{}, en {}!",
+    solved_in: "¡Resuelto en {}/{}!",
+    out_of_guesses: "Sin intentos.",
+    out_of_guesses_word: "Sin intentos. La palabra era {}.",
+    personal_best: "¡Nuevo récord personal!",
+    stats_update_failed: "No se pudieron actualizar las estadísticas: {}",
+    game_log_failed: "No se pudo registrar la partida para repasarla: {}",
+    practice_intro: "Práctica: {} ({} en la reserva). Adivina la palabra de {} letras en {} intentos; escribe PISTA \
+        (o PISTA 2, PISTA 3 para más) para pedir ayuda, RENDIRSE para ver la respuesta y qué falló, o SALIR para parar.",
+    drill_repeats: "letras repetidas",
+    drill_traps: "trampas de una letra",
+    drill_missed: "palabras falladas",
+    no_missed: "No hay palabras falladas que practicar; aquí se añaden los retos diarios perdidos.",
+    no_drill_words: "Ninguna palabra de la lista encaja con ese ejercicio.",
+    off_missed_list: "{} sale de tu lista de falladas.",
+    friend_secret: "Jugador uno: escribe una palabra de {} letras para que la adivine tu amigo (no se mostrará).",
+    not_a_word: "Eso no es una palabra de {} letras.",
+    not_in_list: "Esa palabra no está en la lista, así que no se podría adivinar.",
+    friend_intro: "Jugador dos: adivina la palabra de {} letras de tu amigo en {} intentos, o escribe SALIR para rendirte.",
+    picker_help: "Colorea cada casilla: ←/→ para moverte, G/Y/B o espacio para colorear, Intro para enviar, Esc para escribir.",
+    endless_intro: "Sin fin: resuelve palabra tras palabra en {} intentos cada una. Un fallo acaba la racha; SALIR la para.",
+    endless_word: "\nPalabra {} (racha {}):",
+    endless_solved: "¡Resuelta en {}/{}! Racha: {}",
+    endless_over_one: "Fin de la racha: 1 palabra resuelta, en {} intentos.",
+    endless_over: "Fin de la racha: {} palabras resueltas, {} intentos de media cada una.",
+    endless_over_none: "La racha acabó antes de resolver la primera palabra.",
+    duel_intro: "Duelo: tú y el solucionador tenéis {} intentos cada uno para la misma palabra de {} letras; empiezas tú. \
+        Escribe SALIR para rendirte.",
+    duel_prompt: "Tu intento {} de {}: ",
+    duel_gave_up: "Te has rendido. La palabra era {}.",
+    duel_won: "Has acertado {} en {}, antes que el solucionador. ¡Ganas!",
+    duel_lost: "El solucionador la acertó antes, en {}. La palabra era {}.",
+    duel_draw: "Ninguno de los dos la encontró. La palabra era {}.",
+    multi_intro: "{} tableros: cada intento cuenta en todos los tableros sin resolver. Resuélvelos todos en {} intentos, \
+        o escribe SALIR para rendirte.",
+    boards_solved: "  {} de {} resueltos",
+    multi_won: "¡Resueltos los {} tableros en {}/{}!",
+    solve_multi_intro: "{} tableros en {} intentos. Tras cada intento, introduce su resultado en cada tablero sin \
+        resolver, en orden y separados por espacios. Escribe INTENTO <palabra> <resultados...> si jugaste otra \
+        palabra, o SALIR para parar.",
+    multi_no_fit: "Ninguna palabra encaja con los resultados de todos los tableros.",
+    multi_suggestion: "Intento {} de {}: {}",
+    enter_board_results: "Introduce el resultado del tablero {}: ",
+    enter_boards_results: "Introduce los resultados de los tableros {}: ",
+    multi_guess_usage: "Indica la palabra que jugaste y sus resultados, como INTENTO stare GYBBY BBYGB.",
+    multi_result_count: "Indica un resultado para cada uno de los {} tableros sin resolver.",
+    board_solved: "  tablero {}: resuelto",
+    board_left: "  tablero {}: quedan {}",
+    multi_solved: "¡Los {} tableros resueltos en {}/{}!",
+    palette_now: "Ahora se usa la paleta {}.",
+    palette_standard: "estándar",
+    palette_color_blind: "para daltónicos",
+
+    hints_off: "Las pistas están desactivadas en esta partida.",
+    hints_used_up: "No quedan pistas; esta partida permite {}.",
+    hint_word_left: "Queda 1 palabra posible.",
+    hint_words_left: "Quedan {} palabras posibles.",
+    hint_letter: "La palabra contiene la {}.",
+    hint_all_found: "Ya has encontrado todas las letras; prueba PISTA 1 o PISTA 3.",
+    hint_lost_track: "El solucionador no puede seguir esta partida, así que no hay pista.",
+    hint_try: "Prueba {}.",
+    hint_levels: "Los niveles de pista son 1, 2 y 3.",
+    hint_budget: "{} ({} de {} pistas usadas)",
+    still_fit: "{} palabras siguen encajando con tus pistas: {}{}",
+    and_more: " y {} más",
+    no_shortfall: "Cada intento fue tan informativo como el mejor disponible.",
+    shortfall: "  {} dio {} bits; {} habría dado {}",
+    solver_from_your_opener: "\nEl solucionador desde tu apertura:",
+    solver_own_opener: "\nEl solucionador con su propia apertura:",
+    against_yours: "  {}/{} frente a tu {}/{}",
+
+    no_games: "Todavía no hay retos diarios terminados.",
+    review_usage: "Repasa uno con review <número>.",
+    no_such_game_one: "No hay partida {}; hay 1 registrada.",
+    no_such_game: "No hay partida {}; hay {} registradas.",
+    review_lost_track: "El solucionador no puede seguir esta partida más allá de {}: {}.",
+    review_intro: "{}: {}/{}. Pulsa Intro para cada turno, o Q para parar.",
+    review_turn: "Turno {}:",
+    review_left: "  {} dejó {} de {} palabras ({} bits).",
+    review_solved: "  Resuelta.",
+    review_best: "  El solucionador habría elegido {}, que vale {} bits.",
+    review_as_good: "  Tan informativo como el mejor intento disponible.",
+    stats_summary: "Jugadas {}, ganadas {}%, racha actual {}, racha máxima {}",
+    stats_hints: "Pistas usadas: {}",
+    stats_best_time: "Victoria cronometrada más rápida: {}",
+
+    search_pattern: "El patrón debe tener {} letras o comodines (_ o ?), p. ej. _a_le.",
+    search_not_letter: "'{}' no es una letra; --include y --exclude aceptan letras seguidas como sr.",
+    search_clash: "'{}' no puede estar excluida y exigida a la vez.",
+    search_count: "{} palabras coinciden.",
+    profiling: "Midiendo {} candidatos y {} intentos permitidos, {} ejecuciones cada uno...",
+    phase_picking: "elegir un intento",
+    phase_opener: "apertura",
+    word_list_size: "Las listas de palabras ocupan unos {} KiB.",
+
+    status_attempt: "Intento {} de {}",
+    status_candidates: "Candidatos restantes: {}",
+    status_uncertainty: "Incertidumbre restante: {} bits",
+    status_weighted_uncertainty: "Incertidumbre restante: {} bits ({} bits sin contar los pesos de las palabras)",
+    status_strategy: "Estrategia: {}",
+    status_expected: "Intentos esperados para terminar: {}",
+    status_win_chance: "Probabilidad de ganar en los {} intentos restantes: {}%",
+    kind_answer: "posible respuesta",
+    kind_probe: "sonda (no puede ser la respuesta)",
+    kept_turn: "Se mantiene el turno {}: sin él, {}={} no encaja ({}).",
+    game_would_be_over: "la partida ya habría terminado",
+    removed_turn: "Turno {} eliminado.",
+    removed_replayed_one: "Turno {} eliminado y el siguiente vuelto a aplicar; quedan {} candidatos.",
+    removed_replayed: "Turno {} eliminado y los {} siguientes vueltos a aplicar; quedan {} candidatos.",
+    answer_must_be: "La respuesta tiene que ser {}.",
+    two_left: "Solo quedan {} y {}; prueba cualquiera.",
+    tied_guesses: "{} intentos empatan como mejores:",
+    pick_tie: "Elige un intento por número (pulsa Intro para '{}'): ",
+    invalid_choice: "Opción no válida. Escribe un número del 1 al {}.",
+    picked_by: "'{}' lo eligió la estrategia {}.",
+    explain_information: "  Información esperada: {} bits",
+    explain_split: "  Divide {} candidatos en {} patrones de resultado",
+    explain_worst: "  En el peor caso quedan {} candidatos",
+    explain_answer: "  Podría ser la respuesta",
+    explain_probe: "  No puede ser la respuesta; es una sonda para acotar",
+    explain_runner_up: "  Siguiente mejor por entropía: '{}' con {} bits (peor caso {})",
+    partition_header: "'{}' divide {} candidatos en {} patrones:",
+    expected_information: "Información esperada: {} bits",
+    worst_case: "Peor caso: {} candidatos",
+    no_guesses: "Todavía no hay intentos.",
+    diff_header: "El turno {} ({} {}) descartó {} de {} candidatos.",
+    diff_buckets: "Cada uno habría dado otro resultado:",
+    no_families: "No hay grupos de {} o más candidatos que difieran en una sola letra.",
+    family: "Familia {} ({}): {}",
+    family_warning: "  Aviso: solo quedan {} intentos, así que probarlas una a una podría agotar los turnos",
+    family_probe: "  Sonda: '{}' prueba {} y divide la familia en {} grupos",
+    family_probe_none: "ninguna de las letras que cambian",
+    all_tried: "Ya se han probado todas las letras.",
+    untested: "Sin probar: {}",
+    invalid_regex: "Regex no válida: {}",
+    filter_usage: "Indica {} letras o comodines (_ o ?) como s___t, o una regex como re:^s.*t$.",
+    filter_header: "Coinciden {} de {} candidatos:",
+    no_candidates_left: "No quedan candidatos.",
+    cands_header: "{} candidatos restantes, del {} al {} (página {} de {}):",
+    cands_next: "CANDIDATOS SIGUIENTE para la página siguiente.",
+    nothing_known: "Todavía no se sabe nada.",
+    known_greens: "Verdes:    {}",
+    known_yellows: "Amarillas: {}",
+    yellow_not: "{} (no en {})",
+    known_excluded: "Excluidas: {}",
+    known_counts: "Cuentas:   {}",
+    count_exactly: "{} exactamente {}",
+    count_at_least: "{} al menos {}",
+
+    heading_guess: "intento",
+    heading_result: "resultado",
+    heading_left: "quedan",
+    heading_bits: "bits",
+    heading_best: "mejor",
+    heading_grade: "nota",
+    heading_word: "palabra",
+    heading_entropy: "entropía",
+    heading_worst_case: "peor caso",
+    heading_kind: "tipo",
+    heading_expected: "esperada",
+    heading_actual: "real",
+    heading_before: "antes",
+    heading_after: "después",
+    heading_letter: "letra",
+    heading_total: "total",
+    heading_phase: "fase",
+    heading_min_ms: "mín ms",
+    heading_avg_ms: "media ms",
+    heading_max_ms: "máx ms",
+    heading_strategy: "estrategia",
+    heading_average: "media",
+    heading_time: "tiempo",
+    heading_you: "tú",
+    heading_solver: "solucionador",
+};
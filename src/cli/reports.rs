@@ -0,0 +1,680 @@
+//! Commands that run once and print a report, as text or with `--json` as JSON.
+
+use std::io;
+use std::time::{Duration, Instant};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use solver_project::clock::format_date;
+use solver_project::stats::{load_games, FinishedGame};
+use solver_project::history::{analyze_turns, apply_turns, parse_history};
+use solver_project::oracle::simulate_results;
+use solver_project::{BatchSummary, Constraints, Error, GameRecord, GameStatus, PlayerStats, Strategy, SolverEngine, TurnConflict, WordleSolver};
+use super::messages::{self, fill};
+use super::{exit_code, style};
+use super::format_elapsed;
+
+pub fn replay_transcript(solver: &mut WordleSolver, path: &str, json: bool) -> bool {
+    let messages = messages::catalog();
+    solver.start();
+    let steps = match solver.replay_transcript(path) {
+        Ok(steps) => steps,
+        Err(e) => {
+            eprintln!("{}", fill(messages.replay_failed, &[&e]));
+            return false;
+        }
+    };
+
+    let differences = steps.iter().filter(|step| !step.matches()).count();
+    if json {
+        let turns: Vec<_> = steps
+            .iter()
+            .enumerate()
+            .map(|(idx, step)| {
+                serde_json::json!({
+                    "turn": idx + 1,
+                    "played": step.guess,
+                    "recorded": step.recorded,
+                    "suggested": step.suggested,
+                    "matches": step.matches(),
+                })
+            })
+            .collect();
+        println!("{}", serde_json::json!({ "turns": turns, "differences": differences }));
+        return differences == 0;
+    }
+
+    for (idx, step) in steps.iter().enumerate() {
+        let suggested = step.suggested.as_deref().unwrap_or("-");
+        print!("{}", fill(messages.replay_step, &[&(idx + 1), &step.guess, &suggested]));
+        if !step.matches() {
+            print!("{}", fill(messages.replay_recorded, &[&step.recorded.as_deref().unwrap_or("-")]));
+        }
+        println!();
+    }
+    println!("{}", fill(messages.replay_summary, &[&steps.len(), &differences]));
+    differences == 0
+}
+
+// Applies every turn of a "crane=BYBBG,slimy=BBGYB" history, reporting the first
+// problem and returning the exit status it calls for
+pub fn apply_history(solver: &mut WordleSolver, history: &str) -> Result<(), i32> {
+    let messages = messages::catalog();
+    let Some(turns) = parse_history(history) else {
+        eprintln!("{}", fill(messages.history_unreadable, &[&history]));
+        return Err(exit_code::USAGE);
+    };
+    let stopped = match apply_turns(solver, &turns) {
+        Ok(GameStatus::Ongoing) => return Ok(()),
+        Ok(_) => None,
+        Err(e) => Some(e),
+    };
+    let last_played = || solver.turns().last().map_or(String::new(), |turn| turn.guess.clone());
+    match stopped {
+        // Nothing is left to suggest, so a history that already won can't be used
+        None | Some(Error::HistoryTurn { conflict: TurnConflict::GameOver, .. }) if solver.status() == GameStatus::Won => {
+            eprintln!("{}", fill(messages.history_won, &[&last_played()]));
+            Err(exit_code::USAGE)
+        }
+        None | Some(Error::HistoryTurn { conflict: TurnConflict::GameOver, .. }) => {
+            eprintln!("{}", fill(messages.history_lost, &[&last_played()]));
+            Err(exit_code::LOST)
+        }
+        Some(Error::HistoryTurn { guess, results, conflict: TurnConflict::NoWordFits, .. }) => {
+            eprintln!("{}", fill(messages.history_no_fit, &[&guess, &results]));
+            Err(exit_code::INCONSISTENT)
+        }
+        Some(Error::HistoryTurn { guess, results, conflict: TurnConflict::Refused(e), .. }) => {
+            eprintln!("{}", fill(messages.history_invalid_turn, &[&guess, &results, &e]));
+            Err(exit_code::USAGE)
+        }
+        Some(e) => {
+            eprintln!("{}", e);
+            Err(exit_code::USAGE)
+        }
+    }
+}
+
+// Prints the next guess for a history, returning the exit status
+pub fn suggest_from_history(solver: &mut WordleSolver, history: &str, top: Option<usize>, json: bool) -> i32 {
+    solver.start();
+    if let Err(code) = apply_history(solver, history) {
+        return code;
+    }
+
+    let guess = match solver.current_guess() {
+        Some(guess) => guess.to_string(),
+        None => {
+            eprintln!("{}", messages::catalog().suggest_no_fit);
+            return exit_code::INCONSISTENT;
+        }
+    };
+    let scores = top.map(|count| solver.scored_guesses().into_iter().take(count).collect::<Vec<_>>()).unwrap_or_default();
+    if json {
+        let top: Vec<_> = scores
+            .iter()
+            .map(|score| serde_json::json!({ "guess": score.word, "entropy": score.entropy, "worst_case": score.worst_case }))
+            .collect();
+        println!(
+            "{}",
+            serde_json::json!({ "guess": guess, "candidates_left": solver.candidates().len(), "top": top })
+        );
+        return exit_code::SUCCESS;
+    }
+
+    println!("{}", guess);
+    for score in &scores {
+        println!("{}\t{:.3}\t{}", score.word, score.entropy, score.worst_case);
+    }
+    exit_code::SUCCESS
+}
+
+// Replays a history turn by turn, comparing each guess with the best one the
+// candidates allowed at the time; returns the exit status
+pub fn analyze_history(solver: &mut WordleSolver, history: &str, json: bool) -> i32 {
+    let messages = messages::catalog();
+    let turns = match parse_history(history) {
+        Some(turns) if !turns.is_empty() => turns,
+        _ => {
+            eprintln!("{}", messages.analyze_usage);
+            return exit_code::USAGE;
+        }
+    };
+    let analysis = match analyze_turns(solver, &turns) {
+        Ok(analysis) => analysis,
+        Err(Error::HistoryTurn { guess, results, conflict, .. }) => {
+            return match conflict {
+                TurnConflict::GameOver => {
+                    eprintln!("{}", fill(messages.analyze_over, &[&guess]));
+                    exit_code::USAGE
+                }
+                TurnConflict::NoWordFits => {
+                    eprintln!("{}", fill(messages.history_no_fit, &[&guess, &results]));
+                    exit_code::INCONSISTENT
+                }
+                TurnConflict::Refused(e) => {
+                    eprintln!("{}", fill(messages.history_invalid_turn, &[&guess, &results, &e]));
+                    exit_code::USAGE
+                }
+            };
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            return exit_code::USAGE;
+        }
+    };
+
+    let (overall, overall_share) = (analysis.grade(), analysis.information_share());
+    if json {
+        let turns: Vec<_> = analysis
+            .turns
+            .iter()
+            .map(|analyzed| {
+                let (turn, best) = (&analyzed.turn, &analyzed.best);
+                serde_json::json!({
+                    "guess": turn.guess,
+                    "results": turn.results,
+                    "candidates_after": turn.candidates_after,
+                    "information": turn.expected_information,
+                    "best_guess": best.as_ref().map(|best| &best.word),
+                    "best_information": best.as_ref().map(|best| best.entropy),
+                    "grade": analyzed.grade().to_string(),
+                })
+            })
+            .collect();
+        println!(
+            "{}",
+            serde_json::json!({
+                "turns": turns,
+                "status": format!("{:?}", analysis.status).to_lowercase(),
+                "grade": overall.to_string(),
+                "information_share": overall_share,
+            })
+        );
+    } else {
+        // Columns grow with the word length but never shrink below the headings
+        let width = [messages.heading_guess, messages.heading_result, messages.heading_best]
+            .iter()
+            .map(|heading| heading.chars().count())
+            .fold(solver.word_length(), usize::max);
+        println!(
+            "  #  {:<width$}  {:<width$}  {:>5}  {:>6}  {:<width$}  {:>6}  {}",
+            messages.heading_guess,
+            messages.heading_result,
+            messages.heading_left,
+            messages.heading_bits,
+            messages.heading_best,
+            messages.heading_bits,
+            messages.heading_grade
+        );
+        for (idx, analyzed) in analysis.turns.iter().enumerate() {
+            let turn = &analyzed.turn;
+            let (best_word, best_bits) = match &analyzed.best {
+                Some(best) if best.word != turn.guess && analyzed.share < 1.0 => (best.word.as_str(), format!("{:.2}", best.entropy)),
+                _ => ("-", "-".to_string()),
+            };
+            println!(
+                "  {}  {:<width$}  {:<width$}  {:5}  {:6.2}  {:<width$}  {:>6}  {}",
+                idx + 1,
+                turn.guess,
+                turn.results,
+                turn.candidates_after,
+                turn.expected_information,
+                best_word,
+                best_bits,
+                analyzed.grade()
+            );
+        }
+        let played = analysis.turns.len();
+        let outcome = match analysis.status {
+            GameStatus::Won => fill(messages.analyze_solved, &[&played]),
+            GameStatus::Lost => messages.analyze_lost.to_string(),
+            GameStatus::Ongoing => fill(messages.analyze_unfinished, &[&played]),
+        };
+        let share = format!("{:.1}", overall_share * 100.0);
+        println!("{}", fill(messages.analyze_summary, &[&outcome, &overall, &share]));
+    }
+    match analysis.status {
+        GameStatus::Lost => exit_code::LOST,
+        _ => exit_code::SUCCESS,
+    }
+}
+
+pub fn verify_strategy(solver: &mut WordleSolver, json: bool) -> bool {
+    let messages = messages::catalog();
+    eprintln!("{}", fill(messages.verifying, &[&solver.candidates().len()]));
+    let secrets = solver.candidates().to_vec();
+    let records = solver.play_games(&secrets);
+    // Cancelling stops the run early, so only the games played count
+    let played = records.len();
+    if played < secrets.len() {
+        eprintln!("{}", fill(messages.cancelled_after, &[&played, &secrets.len()]));
+    }
+    let mut failures: Vec<GameRecord> = records.into_iter().filter(|record| !record.won).collect();
+    failures.sort_by(|a, b| a.secret.cmp(&b.secret));
+
+    let solved = played - failures.len();
+    if json {
+        let failed: Vec<_> = failures
+            .iter()
+            .map(|record| serde_json::json!({ "secret": record.secret, "guesses": record.guesses }))
+            .collect();
+        println!(
+            "{}",
+            serde_json::json!({
+                "solved": solved,
+                "total": played,
+                "max_attempts": solver.max_attempts,
+                "failures": failed,
+            })
+        );
+        return failures.is_empty() && played == secrets.len();
+    }
+
+    println!("{}", fill(messages.verify_summary, &[&solved, &played, &solver.max_attempts]));
+    for record in &failures {
+        println!("  {}: {}", record.secret, record.guesses.join(" -> "));
+    }
+    failures.is_empty() && played == secrets.len()
+}
+
+pub fn bench_strategy(solver: &mut WordleSolver, json: bool) {
+    let messages = messages::catalog();
+    eprintln!("{}", fill(messages.bench_playing, &[&solver.candidates().len(), &solver.strategy.label()]));
+    let secrets = solver.candidates().to_vec();
+    let start = Instant::now();
+    let records = solver.play_games(&secrets);
+    let elapsed = start.elapsed();
+    if records.len() < secrets.len() {
+        eprintln!("{}", fill(messages.cancelled_after, &[&records.len(), &secrets.len()]));
+    }
+    let summary = BatchSummary::from_records(&records, solver.max_attempts);
+
+    if json {
+        println!(
+            "{}",
+            serde_json::json!({
+                "strategy": solver.strategy.label(),
+                "games": summary.games,
+                "average": summary.average,
+                "distribution": summary.distribution,
+                "failures": summary.failures,
+                "seconds": elapsed.as_secs_f64(),
+            })
+        );
+        return;
+    }
+
+    println!("{}", fill(messages.average_guesses, &[&format!("{:.3}", summary.average), &summary.games]));
+    let most = summary.distribution.iter().copied().max().unwrap_or(0).max(summary.failures.len()).max(1);
+    let bar = |games: usize| "#".repeat((games * 40).div_ceil(most));
+    for (idx, &games) in summary.distribution.iter().enumerate() {
+        println!("  {:>2}  {:>6}  {}", idx + 1, games, bar(games));
+    }
+    println!("   X  {:>6}  {}", summary.failures.len(), bar(summary.failures.len()));
+    if !summary.failures.is_empty() {
+        println!("{}", fill(messages.failed_words, &[&summary.failures.join(", ")]));
+    }
+    println!("{}", fill(messages.took, &[&format!("{:.2}", elapsed.as_secs_f64())]));
+}
+
+// Lists the logged games, or steps through game number `number` turn by turn: the
+// board so far, how many words each guess left and how it compared with the best
+// guess at the time. Enter shows the next turn and Q stops; returns the exit status
+pub fn review_games(solver: &WordleSolver, log: &str, number: Option<usize>, json: bool) -> i32 {
+    let messages = messages::catalog();
+    let games = match load_games(log) {
+        Ok(games) => games,
+        Err(e) => {
+            eprintln!("{}", e);
+            return exit_code::FAILURE;
+        }
+    };
+    let score = |game: &FinishedGame| match game.guesses.last() {
+        Some(last) if *last == game.secret => game.guesses.len().to_string(),
+        _ => "X".to_string(),
+    };
+    let Some(number) = number else {
+        if json {
+            let listed: Vec<_> = games
+                .iter()
+                .enumerate()
+                .map(|(idx, game)| {
+                    serde_json::json!({
+                        "game": idx + 1,
+                        "date": format_date(game.day),
+                        "secret": game.secret,
+                        "guesses": game.guesses,
+                    })
+                })
+                .collect();
+            println!("{}", serde_json::json!({ "games": listed }));
+        } else if games.is_empty() {
+            println!("{}", messages.no_games);
+        } else {
+            for (idx, game) in games.iter().enumerate() {
+                println!("  {:>3}  {}  {}  {}/{}", idx + 1, format_date(game.day), game.secret, score(game), solver.max_attempts);
+            }
+            println!("{}", messages.review_usage);
+        }
+        return exit_code::SUCCESS;
+    };
+    let Some(game) = number.checked_sub(1).and_then(|idx| games.get(idx)) else {
+        match games.len() {
+            1 => eprintln!("{}", fill(messages.no_such_game_one, &[&number])),
+            logged => eprintln!("{}", fill(messages.no_such_game, &[&number, &logged])),
+        }
+        return exit_code::USAGE;
+    };
+
+    let mut replay = solver.clone();
+    replay.start();
+    let mut rows = Vec::new();
+    for guess in &game.guesses {
+        let before = replay.candidates().len();
+        let best = replay.scored_guesses().into_iter().next();
+        let results = simulate_results(guess, &game.secret);
+        if let Err(e) = replay.apply(guess, &results) {
+            eprintln!("{}", fill(messages.review_lost_track, &[guess, &e]));
+            return exit_code::FAILURE;
+        }
+        let turn = replay.turns().last().unwrap();
+        rows.push((guess, results, before, turn.candidates_after, turn.expected_information, best));
+    }
+    if json {
+        let turns: Vec<_> = rows
+            .iter()
+            .map(|(guess, results, before, after, bits, best)| {
+                serde_json::json!({
+                    "guess": guess,
+                    "results": results,
+                    "candidates_before": before,
+                    "candidates_after": after,
+                    "information": bits,
+                    "best_guess": best.as_ref().map(|best| &best.word),
+                    "best_information": best.as_ref().map(|best| best.entropy),
+                })
+            })
+            .collect();
+        println!(
+            "{}",
+            serde_json::json!({ "date": format_date(game.day), "secret": game.secret, "turns": turns })
+        );
+        return exit_code::SUCCESS;
+    }
+
+    println!("{}", fill(messages.review_intro, &[&format_date(game.day), &score(game), &solver.max_attempts]));
+    for (idx, (guess, _, before, after, bits, best)) in rows.iter().enumerate() {
+        let mut input = String::new();
+        // Without more input, such as when piped, the rest plays out without stopping
+        if io::stdin().read_line(&mut input).expect("Failed to read line") > 0 && input.trim().eq_ignore_ascii_case("Q") {
+            break;
+        }
+        println!("{}", fill(messages.review_turn, &[&(idx + 1)]));
+        for (guess, results, ..) in &rows[..=idx] {
+            println!("  {}", style::tiles(guess, results));
+        }
+        println!("{}", fill(messages.review_left, &[guess, after, before, &format!("{:.2}", bits.max(0.0))]));
+        match best {
+            _ if **guess == game.secret => println!("{}", messages.review_solved),
+            Some(best) if best.entropy > bits + 0.01 => {
+                println!("{}", fill(messages.review_best, &[&best.word, &format!("{:.2}", best.entropy)]))
+            }
+            _ => println!("{}", messages.review_as_good),
+        }
+    }
+    exit_code::SUCCESS
+}
+
+pub fn print_player_stats(stats: &PlayerStats, json: bool) {
+    if json {
+        println!(
+            "{}",
+            serde_json::json!({
+                "played": stats.played,
+                "won": stats.won,
+                "win_rate": stats.win_rate(),
+                "distribution": stats.distribution,
+                "current_streak": stats.current_streak,
+                "max_streak": stats.max_streak,
+                "hints": stats.hints,
+                "best_time_ms": stats.best_time.map(|time| time.as_millis() as u64),
+            })
+        );
+        return;
+    }
+    let messages = messages::catalog();
+    let win_rate = format!("{:.0}", stats.win_rate() * 100.0);
+    println!(
+        "{}",
+        fill(messages.stats_summary, &[&stats.played, &win_rate, &stats.current_streak, &stats.max_streak])
+    );
+    if stats.hints > 0 {
+        println!("{}", fill(messages.stats_hints, &[&stats.hints]));
+    }
+    if let Some(time) = stats.best_time {
+        println!("{}", fill(messages.stats_best_time, &[&format_elapsed(time)]));
+    }
+    let widest = stats.distribution.iter().copied().max().unwrap_or(0).max(1);
+    for (idx, &count) in stats.distribution.iter().enumerate() {
+        let bar = "#".repeat((count * 30).div_ceil(widest));
+        println!("  {} {:<30} {}", idx + 1, bar, count);
+    }
+}
+
+// Lists the words fitting a wildcard pattern and letter sets, exiting like grep:
+// failure when nothing matches
+pub fn search_words(solver: &WordleSolver, pattern: &str, include: &str, exclude: &str, json: bool) -> i32 {
+    let messages = messages::catalog();
+    let length = solver.word_length();
+    let pattern = pattern.trim().to_lowercase();
+    let include = include.trim().to_lowercase();
+    let exclude = exclude.trim().to_lowercase();
+    if pattern.chars().count() != length || !pattern.chars().all(|c| c.is_ascii_lowercase() || c == '_' || c == '?') {
+        eprintln!("{}", fill(messages.search_pattern, &[&length]));
+        return exit_code::USAGE;
+    }
+    if let Some(bad) = include.chars().chain(exclude.chars()).find(|c| !c.is_ascii_lowercase()) {
+        eprintln!("{}", fill(messages.search_not_letter, &[&bad]));
+        return exit_code::USAGE;
+    }
+    if let Some(clash) = exclude.chars().find(|&c| pattern.contains(c) || include.contains(c)) {
+        eprintln!("{}", fill(messages.search_clash, &[&clash]));
+        return exit_code::USAGE;
+    }
+
+    // The same filter the solver applies to feedback: placed letters are greens,
+    // included letters must appear once and excluded ones are capped at none
+    let mut constraints = Constraints::new(length);
+    for (idx, letter) in pattern.chars().enumerate() {
+        if letter.is_ascii_lowercase() {
+            constraints.greens[idx] = Some(letter);
+        }
+    }
+    for letter in include.chars() {
+        constraints.min_counts.insert(letter, 1);
+    }
+    for letter in exclude.chars() {
+        constraints.max_counts.insert(letter, 0);
+    }
+    let matches: Vec<&String> = solver.candidates().iter().filter(|word| constraints.allows(word)).collect();
+
+    if json {
+        println!("{}", serde_json::json!(matches));
+    } else {
+        for word in &matches {
+            println!("{}", word);
+        }
+        eprintln!("{}", fill(messages.search_count, &[&matches.len()]));
+    }
+    if matches.is_empty() { exit_code::FAILURE } else { exit_code::SUCCESS }
+}
+
+// Runs each of the solver's main computations `runs` times and reports the timings
+pub fn profile_solver(solver: &WordleSolver, runs: usize, json: bool) {
+    let candidates = solver.candidates().len();
+    let guesses = solver.allowed_guesses().len();
+    let messages = messages::catalog();
+    eprintln!("{}", fill(messages.profiling, &[&candidates, &guesses, &runs]));
+
+    let measure = |work: &dyn Fn()| -> Vec<f64> {
+        (0..runs)
+            .map(|_| {
+                let start = Instant::now();
+                work();
+                start.elapsed().as_secs_f64() * 1000.0
+            })
+            .collect()
+    };
+    // Each phase keeps an English name for the JSON output and a label for the table
+    let phases = [
+        ("scoring every guess", messages.timing_scoring, measure(&|| drop(solver.scored_guesses()))),
+        ("picking a guess", messages.phase_picking, measure(&|| drop(solver.make_guess()))),
+        ("opener", messages.phase_opener, measure(&|| drop(solver.clone().start().map(str::to_string)))),
+    ];
+    // Word storage only; the solver keeps no tables that grow with the lists
+    let word_bytes: usize = solver
+        .candidates()
+        .iter()
+        .chain(solver.allowed_guesses())
+        .map(|word| word.capacity() + std::mem::size_of::<String>())
+        .sum();
+
+    if json {
+        let timings: Vec<_> = phases
+            .iter()
+            .map(|(name, _, times)| {
+                serde_json::json!({
+                    "phase": name,
+                    "min_ms": times.iter().copied().fold(f64::INFINITY, f64::min),
+                    "avg_ms": times.iter().sum::<f64>() / times.len() as f64,
+                    "max_ms": times.iter().copied().fold(0.0, f64::max),
+                })
+            })
+            .collect();
+        println!(
+            "{}",
+            serde_json::json!({
+                "candidates": candidates,
+                "allowed_guesses": guesses,
+                "runs": runs,
+                "word_list_bytes": word_bytes,
+                "timings": timings,
+            })
+        );
+        return;
+    }
+    println!(
+        "{:<30}{:>10}{:>10}{:>10}",
+        messages.heading_phase, messages.heading_min_ms, messages.heading_avg_ms, messages.heading_max_ms
+    );
+    for (_, label, times) in &phases {
+        println!(
+            "{:<30}{:>10.2}{:>10.2}{:>10.2}",
+            label,
+            times.iter().copied().fold(f64::INFINITY, f64::min),
+            times.iter().sum::<f64>() / times.len() as f64,
+            times.iter().copied().fold(0.0, f64::max)
+        );
+    }
+    println!("{}", fill(messages.word_list_size, &[&format!("{:.1}", word_bytes as f64 / 1024.0)]));
+}
+
+// Pause between revealed guesses with --watch
+pub const WATCH_DELAY: Duration = Duration::from_millis(400);
+
+pub fn simulate_games(solver: &mut WordleSolver, games: usize, seed: Option<u64>, watch: bool, json: bool) {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let secrets: Vec<String> = (0..games).filter_map(|_| solver.candidates().choose(&mut rng).cloned()).collect();
+    let messages = messages::catalog();
+    eprintln!("{}", fill(messages.simulating, &[&secrets.len()]));
+
+    let start = Instant::now();
+    let records = if watch {
+        let opener = solver.start().map(str::to_string);
+        let mut records = Vec::new();
+        for (idx, secret) in secrets.iter().enumerate() {
+            let Some(opener) = &opener else { break };
+            let (guesses, won) = solver.play_out(secret, opener);
+            println!("{}", fill(messages.watch_game, &[&(idx + 1), &secrets.len()]));
+            for guess in &guesses {
+                std::thread::sleep(WATCH_DELAY);
+                println!("  {}", style::tiles(guess, &simulate_results(guess, secret)));
+            }
+            println!("{}", fill(if won { messages.watch_solved } else { messages.watch_missed }, &[&guesses.len()]));
+            records.push(GameRecord { secret: secret.clone(), guesses, won });
+        }
+        records
+    } else {
+        solver.play_games(&secrets)
+    };
+    let elapsed = start.elapsed();
+    let summary = BatchSummary::from_records(&records, solver.max_attempts);
+
+    if json {
+        println!(
+            "{}",
+            serde_json::json!({
+                "games": summary.games,
+                "average": summary.average,
+                "distribution": summary.distribution,
+                "failures": summary.failures,
+                "seconds": elapsed.as_secs_f64(),
+            })
+        );
+        return;
+    }
+    println!("{}", fill(messages.average_guesses, &[&format!("{:.3}", summary.average), &summary.games]));
+    let distribution: Vec<String> = summary.distribution.iter().enumerate().map(|(idx, games)| format!("{}: {}", idx + 1, games)).collect();
+    println!("{}", fill(messages.won_in, &[&distribution.join(", "), &summary.failures.len()]));
+    if !summary.failures.is_empty() {
+        println!("{}", fill(messages.missed_words, &[&summary.failures.join(", ")]));
+    }
+}
+
+pub fn compare_strategies(solver: &WordleSolver, strategies: &[Strategy], json: bool) {
+    let messages = messages::catalog();
+    eprintln!("{}", fill(messages.comparing, &[&solver.candidates().len()]));
+    let distribution_header: String = (1..=solver.max_attempts).map(|guesses| format!("{:>6}", guesses)).collect();
+    if !json {
+        println!(
+            "{:<22}{:>8}{}{:>6}{:>10}",
+            messages.heading_strategy, messages.heading_average, distribution_header, "X", messages.heading_time
+        );
+    }
+
+    let mut rows = Vec::new();
+    for &strategy in strategies {
+        let mut contender = solver.clone();
+        contender.strategy = strategy;
+        let start = Instant::now();
+        let records = contender.play_games(solver.candidates());
+        let elapsed = start.elapsed();
+        let summary = BatchSummary::from_records(&records, solver.max_attempts);
+
+        if json {
+            rows.push(serde_json::json!({
+                "strategy": strategy.label(),
+                "average": summary.average,
+                "distribution": summary.distribution,
+                "failures": summary.failures.len(),
+                "seconds": elapsed.as_secs_f64(),
+            }));
+            continue;
+        }
+        println!(
+            "{:<22}{:>8.3}{}{:>6}{:>9.2}s",
+            strategy.label(),
+            summary.average,
+            summary.distribution.iter().map(|games| format!("{:>6}", games)).collect::<String>(),
+            summary.failures.len(),
+            elapsed.as_secs_f64()
+        );
+    }
+    if json {
+        println!("{}", serde_json::Value::Array(rows));
+    }
+}
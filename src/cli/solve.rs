@@ -0,0 +1,716 @@
+//! The interactive solver: the prompt loop and the commands it takes.
+
+use std::collections::HashMap;
+use std::io;
+use std::sync::Arc;
+use solver_project::constraints::LetterState;
+use solver_project::load::Word;
+use solver_project::history::{apply_turns, parse_history};
+use solver_project::solver::MIN_FAMILY_SIZE;
+use solver_project::oracle::{distribution_entropy, partition_by_pattern, pattern_counts, score_guess};
+use solver_project::{Error, Feedback, FileLogger, GameStatus, SolverEngine, TiePolicy, TurnConflict, WordleSolver};
+use super::messages::{self, fill};
+use super::{exit_code, style};
+use super::args::Args;
+use super::{log_error, print_pass_details, timed};
+use super::games::switch_palette;
+use super::reports::apply_history;
+use super::terminal::{command_argument, pick_results};
+
+/// Runs the interactive solver: prints the banner and first guess, then reads results
+/// and commands from stdin until the game ends.
+pub fn run(mut solver: WordleSolver, args: &Args, history: &str, logger: &Option<Arc<FileLogger>>) {
+    let messages = messages::catalog();
+    // Startup instructions
+    println!(
+        "·▄▄▄▄  ▄▄▄  ▄▄▄ .▄▄▌ ▐ ▄▌.▄▄ ·      \n\
+         ██▪ ██ ▀▄ █·▀▄.▀·██· █▌▐█▐█ ▀.      \n\
+         ▐█· ▐█▌▐▀▀▄ ▐▀▀▪▄██▪▐█▐▐▌▄▀▀▀█▄     \n\
+         ██. ██ ▐█•█▌▐█▄▄▌▐█▌██▐█▌▐█▄▪▐█     \n\
+         ▀▀▀▀▀• .▀  ▀ ▀▀▀  ▀▀▀▀ ▀▪ ▀▀▀▀      \n\
+         ▄▄▌ ▐ ▄▌      ▄▄▄  ·▄▄▄▄  ▄▄▌  ▄▄▄ .\n\
+         ██· █▌▐█▪     ▀▄ █·██▪ ██ ██•  ▀▄.▀·\n\
+         ██▪▐█▐▐▌ ▄█▀▄ ▐▀▀▄ ▐█· ▐█▌██▪  ▐▀▀▪▄\n\
+         ▐█▌██▐█▌▐█▌.▐▌▐█•█▌██. ██ ▐█▌▐▌▐█▄▄▌\n\
+          ▀▀▀▀ ▀▪ ▀█▄▀▪.▀  ▀▀▀▀▀▀• .▀▀▀  ▀▀▀ \n\
+         .▄▄ ·       ▄▄▌   ▌ ▐·▄▄▄ .▄▄▄      \n\
+         ▐█ ▀. ▪     ██•  ▪█·█▌▀▄.▀·▀▄ █·    \n\
+         ▄▀▀▀█▄ ▄█▀▄ ██▪  ▐█▐█•▐▀▀▪▄▐▀▀▄     \n\
+         ▐█▄▪▐█▐█▌.▐▌▐█▌▐▌ ███ ▐█▄▄▌▐█•█▌    \n\
+          ▀▀▀▀  ▀█▄▀▪.▀▀▀ . ▀   ▀▀▀ .▀  ▀    \n\
+         ========================"
+    );
+    println!("{}\n========================\n", messages.instructions);
+
+    timed(args.verbose, messages.timing_opener, || solver.start());
+    if args.verbose > 1 {
+        print_pass_details(&solver);
+    }
+    if let Some(path) = &args.resume {
+        if let Err(e) = solver.load_session(path) {
+            eprintln!("{}", fill(messages.resume_failed, &[&e]));
+            std::process::exit(exit_code::USAGE);
+        }
+        let (turn, label) = (solver.attempts() + 1, solver.active_strategy().label());
+        println!("{}", fill(messages.resumed_at, &[&turn, &solver.current_guess().unwrap(), &label]));
+    } else if !history.is_empty() {
+        if let Err(code) = apply_history(&mut solver, history) {
+            std::process::exit(code);
+        }
+        offer_tied_guesses(&mut solver);
+        let (turn, label) = (solver.attempts() + 1, solver.active_strategy().label());
+        println!("{}", fill(messages.starting_at, &[&turn, &solver.current_guess().unwrap(), &label]));
+    } else {
+        offer_tied_guesses(&mut solver);
+        let label = solver.active_strategy().label();
+        println!("{}", fill(messages.initial_guess, &[&solver.current_guess().unwrap(), &label]));
+    }
+
+    // Last page of candidates shown, for CANDS NEXT
+    let mut candidate_page = 0;
+    loop {
+        let mut results = String::new();
+        println!("{}", fill(messages.enter_results, &[&solver.current_guess().unwrap()]));
+        match solver.current_guess().filter(|_| args.picker).and_then(pick_results) {
+            Some(picked) => results = picked,
+            // Stop at end of input instead of re-prompting forever
+            None if io::stdin().read_line(&mut results).expect("Failed to read line") == 0 => break,
+            None => {}
+        }
+        // A Ctrl-C at the prompt shouldn't cut short the next command
+        solver.cancel_token().reset();
+        // Commands typed in the prompt language are matched as their English keywords
+        let input = messages::resolve_command(results.trim());
+        let input = input.as_str();
+        let results = input.to_uppercase();
+
+        if results == "EXIT" {
+            break;
+        }
+        if results == "STATUS" {
+            print_status(&solver);
+            continue;
+        }
+        if results == "TOP" {
+            timed(args.verbose, messages.timing_scoring, || print_top_guesses(&solver));
+            continue;
+        }
+        if results == "EXPLAIN" {
+            print_explanation(&solver);
+            continue;
+        }
+        if let Some(word) = results.strip_prefix("WHATIF ") {
+            match Word::parse(word, solver.word_length()) {
+                Ok(word) => print_partition(&solver, word.as_str()),
+                Err(_) => println!("{}", fill(messages.whatif_usage, &[&solver.word_length()])),
+            }
+            continue;
+        }
+        if results == "FAMILIES" {
+            print_families(&solver);
+            continue;
+        }
+        if results == "BOARD" || results == "BOARD INFO" {
+            print_board(&solver, results == "BOARD INFO");
+            if !solver.turns().is_empty() {
+                println!("\n{}", style::keyboard(solver.constraints()));
+            }
+            continue;
+        }
+        if results == "PALETTE" {
+            switch_palette();
+            continue;
+        }
+        if results == "KEYS" {
+            print_keys(&solver);
+            continue;
+        }
+        if let Some(page) = results.strip_prefix("CANDS").filter(|rest| rest.is_empty() || rest.starts_with(' ')) {
+            let page = match page.trim() {
+                "" => Some(1),
+                "NEXT" => Some(candidate_page + 1),
+                page => page.parse().ok().filter(|&page| page >= 1),
+            };
+            match page {
+                Some(page) => candidate_page = print_candidates(&solver, page),
+                None => println!("{}", messages.cands_usage),
+            }
+            continue;
+        }
+        if let Some(pattern) = command_argument(input, "FILTER") {
+            print_filtered_candidates(&solver, pattern);
+            continue;
+        }
+        if results == "KNOWN" {
+            print_known(&solver);
+            continue;
+        }
+        if results == "DIFF" {
+            print_elimination_diff(&solver);
+            continue;
+        }
+        if results == "UNDO" || results.starts_with("UNDO ") {
+            let turn = match results.strip_prefix("UNDO ") {
+                Some(turn) => turn.trim().parse::<usize>().ok(),
+                None => Some(solver.turns().len()),
+            };
+            let turn = turn.filter(|&turn| turn >= 1);
+            match turn {
+                _ if solver.turns().is_empty() => println!("{}", messages.nothing_to_undo),
+                Some(turn) => {
+                    if remove_turn(&mut solver, turn) {
+                        offer_tied_guesses(&mut solver);
+                        let label = solver.active_strategy().label();
+                        println!("{}", fill(messages.next_guess, &[&solver.current_guess().unwrap(), &label]));
+                    }
+                }
+                None => println!("{}", fill(messages.invalid_turn_number, &[&solver.turns().len()])),
+            }
+            continue;
+        }
+        if let Some(path) = command_argument(input, "SAVE") {
+            match solver.save_session(path) {
+                Ok(()) => println!("{}", fill(messages.saved, &[&path])),
+                Err(e) => {
+                    log_error(logger, &format!("failed to save the game: {}", e));
+                    println!("{}", fill(messages.save_failed, &[&e]));
+                }
+            }
+            continue;
+        }
+        if let Some(path) = command_argument(input, "TRANSCRIPT") {
+            match solver.export_transcript(path) {
+                Ok(()) => println!("{}", fill(messages.transcript_written, &[&path])),
+                Err(e) => {
+                    log_error(logger, &format!("failed to write the transcript: {}", e));
+                    println!("{}", fill(messages.transcript_failed, &[&e]));
+                }
+            }
+            continue;
+        }
+        if let Some(path) = command_argument(input, "RESUME") {
+            match solver.load_session(path) {
+                Ok(()) => {
+                    let (turn, label) = (solver.attempts() + 1, solver.active_strategy().label());
+                    println!("{}", fill(messages.resumed_at, &[&turn, &solver.current_guess().unwrap(), &label]));
+                }
+                Err(e) => {
+                    log_error(logger, &format!("failed to resume session: {}", e));
+                    println!("{}", fill(messages.resume_failed, &[&e]));
+                }
+            }
+            continue;
+        }
+        if results == "RESTART" {
+            solver.reset();
+            offer_tied_guesses(&mut solver);
+            println!("{}", fill(messages.starting_over, &[&solver.current_guess().unwrap()]));
+            continue;
+        }
+        // Pasted turns like crane=BYBBG; slimy=BBGYB. A block of one pair per line is
+        // applied line by line as it arrives
+        if input.contains('=') {
+            let Some(status) = apply_pasted_turns(&mut solver, input) else { continue };
+            match status {
+                GameStatus::Won => {
+                    println!("{}", messages.won);
+                    break;
+                }
+                GameStatus::Lost => {
+                    println!("{}", messages.lost);
+                    std::process::exit(exit_code::LOST);
+                }
+                GameStatus::Ongoing => {
+                    announce_forced_guess(&solver);
+                    offer_tied_guesses(&mut solver);
+                    let label = solver.active_strategy().label();
+                    println!("{}", fill(messages.next_guess, &[&solver.current_guess().unwrap(), &label]));
+                }
+            }
+            continue;
+        }
+        // GUESS <word> <results> records a word played instead of the suggestion
+        let status = match command_argument(input, "GUESS") {
+            Some(turn) => {
+                let Some((word, turn_results)) = turn.split_once(char::is_whitespace) else {
+                    println!("{}", messages.guess_usage);
+                    continue;
+                };
+                let suggested = solver.current_guess().map(str::to_string);
+                let status = timed(args.verbose, messages.timing_applying, || {
+                    solver.apply(word, &turn_results.trim().to_uppercase())
+                });
+                // A refused turn leaves the suggestion as it was
+                if let Err(e) = &status {
+                    if let Some(suggested) = suggested {
+                        solver.choose_guess(&suggested);
+                    }
+                    log_error(logger, &format!("invalid turn: {}", e));
+                    println!("{}", fill(messages.invalid_turn, &[&e]));
+                    continue;
+                }
+                status
+            }
+            None => timed(args.verbose, messages.timing_applying, || solver.next_turn(&results)),
+        };
+        if args.verbose > 1 && status.is_ok() {
+            print_pass_details(&solver);
+        }
+        match status {
+            Ok(GameStatus::Won) => {
+                println!("{}", messages.won);
+                break;
+            }
+            Ok(GameStatus::Lost) => {
+                println!("{}", messages.lost);
+                std::process::exit(exit_code::LOST);
+            }
+            Ok(GameStatus::Ongoing) => {
+                if solver.cancel_token().is_cancelled() {
+                    println!("{}", messages.scoring_cancelled);
+                }
+                announce_forced_guess(&solver);
+                offer_tied_guesses(&mut solver);
+                let label = solver.active_strategy().label();
+                println!("{}", fill(messages.next_guess, &[&solver.current_guess().unwrap(), &label]));
+            }
+            Err(e) => {
+                log_error(logger, &format!("invalid results: {}", e));
+                println!("{}", fill(messages.invalid_results, &[&e]));
+            }
+        }
+    }
+}
+
+// Applies turns pasted in solver mode all at once, or none of them: every malformed
+// turn is reported together, then the first that no word fits or that comes after
+// the game is over. Returns where the game stands once they're applied
+pub fn apply_pasted_turns(solver: &mut WordleSolver, pasted: &str) -> Option<GameStatus> {
+    let messages = messages::catalog();
+    let Some(turns) = parse_history(pasted) else {
+        println!("{}", messages.paste_unreadable);
+        return None;
+    };
+    let problems: Vec<String> = turns
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, (guess, results))| {
+            let error = Word::parse(guess, solver.word_length())
+                .err()
+                .or_else(|| Feedback::parse(&results.to_uppercase(), solver.word_length()).err())?;
+            Some(fill(messages.paste_problem, &[&(idx + 1), &error]))
+        })
+        .collect();
+    if !problems.is_empty() {
+        println!("{}", fill(messages.paste_rejected, &[&turns.len(), &problems.join("\n")]));
+        return None;
+    }
+
+    let mut trial = solver.clone();
+    let status = match apply_turns(&mut trial, &turns) {
+        Ok(status) => status,
+        Err(Error::HistoryTurn { turn, guess, results, conflict }) => {
+            let problem = match conflict {
+                TurnConflict::GameOver if trial.status() == GameStatus::Won => messages.game_already_won.to_string(),
+                TurnConflict::GameOver => messages.game_already_lost.to_string(),
+                TurnConflict::NoWordFits => messages.no_word_fits.to_string(),
+                TurnConflict::Refused(e) => e.to_string(),
+            };
+            let problem = fill(messages.paste_problem_turn, &[&turn, &guess, &results, &problem]);
+            println!("{}", fill(messages.paste_rejected, &[&turns.len(), &problem]));
+            return None;
+        }
+        Err(e) => {
+            println!("{}", fill(messages.paste_rejected, &[&turns.len(), &e]));
+            return None;
+        }
+    };
+    *solver = trial;
+    let left = solver.candidates().len();
+    match turns.len() {
+        1 => println!("{}", fill(messages.applied_turn, &[&left])),
+        applied => println!("{}", fill(messages.applied_turns, &[&applied, &left])),
+    }
+    Some(status)
+}
+
+pub fn print_status(solver: &WordleSolver) {
+    let messages = messages::catalog();
+    println!("{}", fill(messages.status_attempt, &[&(solver.attempts() + 1), &solver.max_attempts]));
+    println!("{}", fill(messages.status_candidates, &[&solver.candidates().len()]));
+    if !solver.candidates().is_empty() {
+        let uniform_entropy = format!("{:.2}", (solver.candidates().len() as f64).log2());
+        if solver.weights().is_empty() {
+            println!("{}", fill(messages.status_uncertainty, &[&uniform_entropy]));
+        } else {
+            let entropy = format!("{:.2}", distribution_entropy(solver.candidates(), solver.weights()));
+            println!("{}", fill(messages.status_weighted_uncertainty, &[&entropy, &uniform_entropy]));
+        }
+    }
+    println!("{}", fill(messages.status_strategy, &[&solver.active_strategy().label()]));
+    println!("{}", fill(messages.status_expected, &[&format!("{:.2}", solver.expected_remaining_guesses())]));
+    let chance = format!("{:.1}", solver.win_probability() * 100.0);
+    println!("{}", fill(messages.status_win_chance, &[&(solver.max_attempts - solver.attempts()), &chance]));
+}
+
+pub const TOP_GUESS_COUNT: usize = 10;
+
+// Whether a suggested guess could win outright or only gathers information
+pub fn guess_kind(solver: &WordleSolver, word: &str) -> &'static str {
+    if solver.candidates().iter().any(|candidate| candidate == word) {
+        messages::catalog().kind_answer
+    } else {
+        messages::catalog().kind_probe
+    }
+}
+
+pub fn print_top_guesses(solver: &WordleSolver) {
+    let messages = messages::catalog();
+    let width = solver.word_length().max(messages.heading_word.chars().count());
+    let entropy_width = messages.heading_entropy.chars().count().max(7);
+    let worst_width = messages.heading_worst_case.chars().count().max(10);
+    println!(
+        "  {:<width$}  {:<entropy_width$}  {:<worst_width$}  {}",
+        messages.heading_word, messages.heading_entropy, messages.heading_worst_case, messages.heading_kind
+    );
+    for score in solver.scored_guesses().iter().take(TOP_GUESS_COUNT) {
+        println!(
+            "  {:<width$}  {:entropy_width$.3}  {:worst_width$}  {}",
+            score.word,
+            score.entropy,
+            score.worst_case,
+            guess_kind(solver, &score.word)
+        );
+    }
+}
+
+// Takes turn `turn` (from 1) out of the game and plays the later turns again without
+// it, keeping the game as it was if they no longer fit together. Returns whether the
+// turn was removed
+pub fn remove_turn(solver: &mut WordleSolver, turn: usize) -> bool {
+    let messages = messages::catalog();
+    match solver.remove_turn(turn) {
+        Ok(replayed) => {
+            let left = solver.candidates().len();
+            match replayed {
+                0 => println!("{}", fill(messages.removed_turn, &[&turn])),
+                1 => println!("{}", fill(messages.removed_replayed_one, &[&turn, &left])),
+                replayed => println!("{}", fill(messages.removed_replayed, &[&turn, &replayed, &left])),
+            }
+            true
+        }
+        Err(Error::TurnKept { guess, results, conflict, .. }) => {
+            let problem = match conflict {
+                TurnConflict::NoWordFits => messages.no_word_fits.to_string(),
+                TurnConflict::GameOver => messages.game_would_be_over.to_string(),
+                TurnConflict::Refused(e) => e.to_string(),
+            };
+            println!("{}", fill(messages.kept_turn, &[&turn, &guess, &results, &problem]));
+            false
+        }
+        Err(Error::NoSuchTurn { turns, .. }) => {
+            println!("{}", fill(messages.invalid_turn_number, &[&turns]));
+            false
+        }
+        Err(e) => unreachable!("removing a turn fails only with NoSuchTurn or TurnKept, not {:?}", e),
+    }
+}
+
+// Says so when the candidates are down to one or two, where the guess is forced
+pub fn announce_forced_guess(solver: &WordleSolver) {
+    match solver.candidates() {
+        [answer] => println!("{}", fill(messages::catalog().answer_must_be, &[answer])),
+        [first, second] => println!("{}", fill(messages::catalog().two_left, &[first, second])),
+        _ => {}
+    }
+}
+
+// Lets the player pick among tied best guesses when the tie policy asks for it
+pub fn offer_tied_guesses(solver: &mut WordleSolver) {
+    if solver.tie_policy != TiePolicy::Prompt {
+        return;
+    }
+    let ties = solver.tie_set();
+    if ties.len() < 2 {
+        return;
+    }
+
+    let messages = messages::catalog();
+    println!("{}", fill(messages.tied_guesses, &[&ties.len()]));
+    for (idx, word) in ties.iter().enumerate() {
+        println!("  {}. {}  {}", idx + 1, word, guess_kind(solver, word));
+    }
+    loop {
+        println!("{}", fill(messages.pick_tie, &[&ties[0]]));
+        let mut choice = String::new();
+        io::stdin().read_line(&mut choice).expect("Failed to read line");
+        let choice = choice.trim();
+        if choice.is_empty() {
+            solver.choose_guess(&ties[0]);
+            return;
+        }
+        match choice.parse::<usize>() {
+            Ok(number) if (1..=ties.len()).contains(&number) => {
+                solver.choose_guess(&ties[number - 1]);
+                return;
+            }
+            _ => println!("{}", fill(messages.invalid_choice, &[&ties.len()])),
+        }
+    }
+}
+
+pub fn print_explanation(solver: &WordleSolver) {
+    let guess = solver.current_guess().unwrap();
+    let counts = pattern_counts(guess, solver.candidates(), solver.weights());
+    let score = score_guess(guess, solver.candidates(), solver.weights());
+
+    let messages = messages::catalog();
+    println!("{}", fill(messages.picked_by, &[&guess, &solver.active_strategy().label()]));
+    println!("{}", fill(messages.explain_information, &[&format!("{:.3}", score.entropy)]));
+    println!("{}", fill(messages.explain_split, &[&solver.candidates().len(), &counts.len()]));
+    println!("{}", fill(messages.explain_worst, &[&score.worst_case]));
+    if solver.candidates().iter().any(|word| word == guess) {
+        println!("{}", messages.explain_answer);
+    } else {
+        println!("{}", messages.explain_probe);
+    }
+
+    // Compare against the best-scoring alternative
+    if let Some(runner_up) = solver.scored_guesses().into_iter().find(|other| other.word != guess) {
+        let entropy = format!("{:.3}", runner_up.entropy);
+        println!("{}", fill(messages.explain_runner_up, &[&runner_up.word, &entropy, &runner_up.worst_case]));
+    }
+}
+
+// How many example words to show per feedback bucket
+pub const BUCKET_SAMPLE_SIZE: usize = 5;
+
+pub fn print_partition(solver: &WordleSolver, word: &str) {
+    let mut buckets: Vec<(String, Vec<String>)> = partition_by_pattern(word, solver.candidates()).into_iter().collect();
+    buckets.sort_by(|(pattern_a, words_a), (pattern_b, words_b)| {
+        words_b.len().cmp(&words_a.len()).then_with(|| pattern_a.cmp(pattern_b))
+    });
+
+    let messages = messages::catalog();
+    println!("{}", fill(messages.partition_header, &[&word, &solver.candidates().len(), &buckets.len()]));
+    for (pattern, words) in &buckets {
+        let sample = words.iter().take(BUCKET_SAMPLE_SIZE).cloned().collect::<Vec<_>>().join(", ");
+        let more = if words.len() > BUCKET_SAMPLE_SIZE { ", ..." } else { "" };
+        println!("  {}  {:5}  {}{}", pattern, words.len(), sample, more);
+    }
+
+    let score = score_guess(word, solver.candidates(), solver.weights());
+    println!("{}", fill(messages.expected_information, &[&format!("{:.3}", score.entropy)]));
+    println!("{}", fill(messages.worst_case, &[&score.worst_case]));
+}
+
+pub fn print_elimination_diff(solver: &WordleSolver) {
+    let messages = messages::catalog();
+    let turn = match solver.turns().last() {
+        Some(turn) => turn,
+        None => {
+            println!("{}", messages.no_guesses);
+            return;
+        }
+    };
+    let eliminated = solver.last_eliminated();
+    let before = eliminated.len() + solver.candidates().len();
+    println!(
+        "{}",
+        fill(messages.diff_header, &[&solver.turns().len(), &turn.guess, &turn.results, &eliminated.len(), &before])
+    );
+
+    // Group by the feedback each word would have given, largest group first
+    let mut buckets: HashMap<&str, Vec<&str>> = HashMap::new();
+    for elimination in &eliminated {
+        buckets.entry(&elimination.pattern).or_default().push(&elimination.word);
+    }
+    let mut buckets: Vec<(&str, Vec<&str>)> = buckets.into_iter().collect();
+    buckets.sort_by(|(pattern_a, words_a), (pattern_b, words_b)| {
+        words_b.len().cmp(&words_a.len()).then_with(|| pattern_a.cmp(pattern_b))
+    });
+    if !buckets.is_empty() {
+        println!("{}", messages.diff_buckets);
+    }
+    for (pattern, words) in &buckets {
+        let sample = words.iter().take(BUCKET_SAMPLE_SIZE).copied().collect::<Vec<_>>().join(", ");
+        let more = if words.len() > BUCKET_SAMPLE_SIZE { ", ..." } else { "" };
+        println!("  {}  {:5}  {}{}", pattern, words.len(), sample, more);
+    }
+}
+
+pub fn print_families(solver: &WordleSolver) {
+    let messages = messages::catalog();
+    let families = solver.candidate_families();
+    if families.is_empty() {
+        println!("{}", fill(messages.no_families, &[&MIN_FAMILY_SIZE]));
+        return;
+    }
+
+    let guesses_left = solver.max_attempts - solver.attempts();
+    for family in &families {
+        println!("{}", fill(messages.family, &[&family.pattern, &family.words.len(), &family.words.join(", ")]));
+        if family.words.len() > guesses_left {
+            println!("{}", fill(messages.family_warning, &[&guesses_left]));
+        }
+        if let Some(probe) = solver.family_probe(family) {
+            let idx = family.pattern.find('_').unwrap();
+            let covered: Vec<String> = family.words
+                .iter()
+                .map(|word| word[idx..idx + 1].to_string())
+                .filter(|letter| probe.contains(letter.as_str()))
+                .collect();
+            let groups = partition_by_pattern(&probe, &family.words).len();
+            let tested = if covered.is_empty() { messages.family_probe_none.to_string() } else { covered.join(", ") };
+            println!("{}", fill(messages.family_probe, &[&probe, &tested, &groups]));
+        }
+    }
+}
+
+pub fn print_board(solver: &WordleSolver, show_information: bool) {
+    let messages = messages::catalog();
+    if solver.turns().is_empty() {
+        println!("{}", messages.no_guesses);
+        return;
+    }
+
+    // Columns grow with the word length but never shrink below the headings
+    let fit = |heading: &str, least: usize| heading.chars().count().max(least);
+    let width = fit(messages.heading_guess, fit(messages.heading_result, solver.word_length()));
+    let left = fit(messages.heading_left, 5);
+    let (expected, actual) = (fit(messages.heading_expected, 8), fit(messages.heading_actual, 6));
+    let (before, after) = (fit(messages.heading_before, 6), fit(messages.heading_after, 6));
+    print!("  #  {:<width$}  {:<width$}  {:>left$}", messages.heading_guess, messages.heading_result, messages.heading_left);
+    if show_information {
+        print!(
+            "  {:>expected$}  {:>actual$}  {:>before$}  {:>after$}",
+            messages.heading_expected, messages.heading_actual, messages.heading_before, messages.heading_after
+        );
+    }
+    println!();
+    for (idx, turn) in solver.turns().iter().enumerate() {
+        print!("  {}  {:<width$}  {:<width$}  {:left$}", idx + 1, turn.guess, turn.results, turn.candidates_after);
+        if show_information {
+            let bits = turn.actual_information.map_or("-".to_string(), |bits| format!("{:.2}", bits));
+            print!(
+                "  {:expected$.2}  {:>actual$}  {:before$.2}  {:after$.2}",
+                turn.expected_information, bits, turn.entropy_before, turn.entropy_after
+            );
+        }
+        println!();
+    }
+}
+
+// Shows the alphabet by what the feedback says of each letter, and lists the letters
+// no guess has tried yet
+pub fn print_keys(solver: &WordleSolver) {
+    println!("{}", style::alphabet(solver.constraints()));
+    let untested: String = ('a'..='z').filter(|&letter| solver.constraints().letter_state(letter) == LetterState::Unused).collect();
+    if untested.is_empty() {
+        println!("{}", messages::catalog().all_tried);
+    } else {
+        println!("{}", fill(messages::catalog().untested, &[&untested.to_uppercase()]));
+    }
+}
+
+// Lists the candidates matching a pattern, without narrowing the solver's candidates:
+// either letters and wildcards (_ or ?) like s___t, or a regex after re:
+pub fn print_filtered_candidates(solver: &WordleSolver, pattern: &str) {
+    let messages = messages::catalog();
+    let matches: Box<dyn Fn(&str) -> bool> = match pattern.strip_prefix("re:") {
+        Some(expression) => match regex::RegexBuilder::new(expression).case_insensitive(true).build() {
+            Ok(expression) => Box::new(move |word| expression.is_match(word)),
+            Err(e) => {
+                println!("{}", fill(messages.invalid_regex, &[&e]));
+                return;
+            }
+        },
+        None => {
+            let pattern = pattern.to_lowercase();
+            if pattern.chars().count() != solver.word_length()
+                || !pattern.chars().all(|c| c.is_ascii_lowercase() || c == '_' || c == '?')
+            {
+                println!("{}", fill(messages.filter_usage, &[&solver.word_length()]));
+                return;
+            }
+            Box::new(move |word| word.chars().zip(pattern.chars()).all(|(letter, want)| want == '_' || want == '?' || letter == want))
+        }
+    };
+    let probabilities = solver.candidate_probabilities();
+    let shown: Vec<&(String, f64)> = probabilities.iter().filter(|(word, _)| matches(word)).collect();
+    println!("{}", fill(messages.filter_header, &[&shown.len(), &probabilities.len()]));
+    for (word, probability) in shown {
+        println!("  {}  {:6.2}%", word, probability * 100.0);
+    }
+}
+
+pub const CANDIDATE_PAGE_SIZE: usize = 20;
+
+// Prints one page of the remaining candidates, with their chances of being the answer;
+// a page past the end shows the last one. Returns the page shown
+pub fn print_candidates(solver: &WordleSolver, page: usize) -> usize {
+    let messages = messages::catalog();
+    let probabilities = solver.candidate_probabilities();
+    if probabilities.is_empty() {
+        println!("{}", messages.no_candidates_left);
+        return 1;
+    }
+    let pages = probabilities.len().div_ceil(CANDIDATE_PAGE_SIZE);
+    let page = page.clamp(1, pages);
+    let start = (page - 1) * CANDIDATE_PAGE_SIZE;
+    let shown = &probabilities[start..(start + CANDIDATE_PAGE_SIZE).min(probabilities.len())];
+    let (first, last) = (start + 1, start + shown.len());
+    println!("{}", fill(messages.cands_header, &[&probabilities.len(), &first, &last, &page, &pages]));
+    for (word, probability) in shown {
+        println!("  {}  {:6.2}%", word, probability * 100.0);
+    }
+    if page < pages {
+        println!("{}", messages.cands_next);
+    }
+    page
+}
+
+pub fn print_known(solver: &WordleSolver) {
+    let messages = messages::catalog();
+    if solver.turns().is_empty() {
+        println!("{}", messages.nothing_known);
+        return;
+    }
+
+    let constraints = solver.constraints();
+    let greens: Vec<String> = constraints.greens.iter().map(|green| green.unwrap_or('_').to_string()).collect();
+    println!("{}", fill(messages.known_greens, &[&greens.join(" ")]));
+
+    let yellows: Vec<String> = constraints
+        .yellows()
+        .into_iter()
+        .filter(|(_, banned)| !banned.is_empty())
+        .map(|(letter, banned)| {
+            let positions: Vec<String> = banned.iter().map(|idx| (idx + 1).to_string()).collect();
+            fill(messages.yellow_not, &[&letter, &positions.join(", ")])
+        })
+        .collect();
+    if !yellows.is_empty() {
+        println!("{}", fill(messages.known_yellows, &[&yellows.join(", ")]));
+    }
+
+    let excluded: Vec<String> = constraints.excluded().iter().map(char::to_string).collect();
+    if !excluded.is_empty() {
+        println!("{}", fill(messages.known_excluded, &[&excluded.join(" ")]));
+    }
+
+    // Counts worth spelling out: repeated letters, and letters a gray tile capped
+    let counts: Vec<String> = constraints
+        .min_counts
+        .iter()
+        .filter_map(|(letter, &min)| match constraints.max_counts.get(letter) {
+            Some(&max) if max > 0 => Some(fill(messages.count_exactly, &[letter, &max])),
+            Some(_) => None,
+            None if min > 1 => Some(fill(messages.count_at_least, &[letter, &min])),
+            None => None,
+        })
+        .collect();
+    if !counts.is_empty() {
+        println!("{}", fill(messages.known_counts, &[&counts.join(", ")]));
+    }
+}
@@ -0,0 +1,123 @@
+//! Everything that writes escape codes goes through here, so color can be turned off
+//! in one place for NO_COLOR, --no-color and output that isn't a terminal, and the
+//! palette swapped for color-blind players
+
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use solver_project::constraints::{Constraints, LetterState};
+use solver_project::game::Palette;
+
+static COLOR: AtomicBool = AtomicBool::new(false);
+static COLOR_BLIND: AtomicBool = AtomicBool::new(false);
+static ANIMATE: AtomicBool = AtomicBool::new(false);
+
+// Pause between revealing one tile and the next
+const REVEAL_DELAY: Duration = Duration::from_millis(200);
+
+/// Decides once at startup whether to write color, in which palette, and whether
+/// to reveal tiles one at a time.
+pub fn init(no_color: bool, palette: Palette, no_animation: bool) {
+    let disabled_by_env = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    let terminal = std::io::stdout().is_terminal();
+    COLOR.store(!no_color && !disabled_by_env && terminal, Ordering::Relaxed);
+    ANIMATE.store(!no_animation && terminal, Ordering::Relaxed);
+    set_palette(palette);
+}
+
+pub fn color_enabled() -> bool {
+    COLOR.load(Ordering::Relaxed)
+}
+
+pub fn palette() -> Palette {
+    if COLOR_BLIND.load(Ordering::Relaxed) { Palette::ColorBlind } else { Palette::Standard }
+}
+
+pub fn set_palette(palette: Palette) {
+    COLOR_BLIND.store(palette == Palette::ColorBlind, Ordering::Relaxed);
+}
+
+const KEYBOARD_ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// A QWERTY keyboard showing each letter's best-known state. Without color,
+/// greens are in brackets, yellows in parentheses and ruled-out letters dotted out.
+pub fn keyboard(constraints: &Constraints) -> String {
+    let rows: Vec<String> = KEYBOARD_ROWS
+        .iter()
+        .enumerate()
+        .map(|(indent, row)| {
+            let keys: String = row.chars().map(|letter| key(constraints, letter)).collect();
+            format!("{}{}", " ".repeat(indent * 2), keys)
+        })
+        .collect();
+    rows.join("\n")
+}
+
+/// The letters a to z in one row, each in its best-known state as on the keyboard.
+pub fn alphabet(constraints: &Constraints) -> String {
+    ('a'..='z').map(|letter| key(constraints, letter)).collect()
+}
+
+// One key in its letter's state
+fn key(constraints: &Constraints, letter: char) -> String {
+    match constraints.letter_state(letter) {
+        LetterState::Correct => tile(letter, 'G'),
+        LetterState::Present => tile(letter, 'Y'),
+        LetterState::Absent if !color_enabled() => " · ".to_string(),
+        LetterState::Absent => tile(letter, 'B'),
+        LetterState::Unused => format!(" {} ", letter.to_ascii_uppercase()),
+    }
+}
+
+/// A guess drawn as tiles, one per letter of its feedback: colored when color
+/// is on, otherwise greens in brackets and yellows in parentheses.
+pub fn tiles(guess: &str, results: &str) -> String {
+    guess.chars().zip(results.chars()).map(|(letter, status)| tile(letter, status)).collect()
+}
+
+/// Prints a guess's tiles on their own line, indented, revealing them left to
+/// right with a short pause each when animation is on.
+pub fn reveal(guess: &str, results: &str) {
+    if !ANIMATE.load(Ordering::Relaxed) {
+        println!("  {}", tiles(guess, results));
+        return;
+    }
+    let mut stdout = std::io::stdout();
+    print!("  ");
+    for (letter, status) in guess.chars().zip(results.chars()) {
+        let _ = stdout.flush();
+        std::thread::sleep(REVEAL_DELAY);
+        print!("{}", tile(letter, status));
+    }
+    println!();
+}
+
+/// Clears the terminal so nothing typed earlier can be scrolled back to; does
+/// nothing when stdout isn't a terminal.
+pub fn clear_screen() {
+    if std::io::stdout().is_terminal() {
+        print!("\x1b[2J\x1b[3J\x1b[H");
+        let _ = std::io::stdout().flush();
+    }
+}
+
+// One letter with its feedback status, 'G', 'Y' or anything else for gray
+fn tile(letter: char, status: char) -> String {
+    let letter = letter.to_ascii_uppercase();
+    if !color_enabled() {
+        return match status {
+            'G' => format!("[{}]", letter),
+            'Y' => format!("({})", letter),
+            _ => format!(" {} ", letter),
+        };
+    }
+    // Orange and blue backgrounds for the color-blind palette, from the 256-color set
+    let background = match (palette(), status) {
+        (Palette::Standard, 'G') => "42",
+        (Palette::Standard, 'Y') => "43",
+        (Palette::ColorBlind, 'G') => "48;5;208",
+        (Palette::ColorBlind, 'Y') => "48;5;39",
+        _ => "100",
+    };
+    format!("\x1b[1;30;{}m {} \x1b[0m", background, letter)
+}
@@ -0,0 +1,129 @@
+//! Raw terminal input: the result picker, hidden entry and the prompt's command words.
+
+use std::io;
+use super::messages;
+use super::style;
+
+// Lets the player color the tiles of `guess` in place: left and right move between
+// tiles, G, Y and B color one and move on, space or up and down cycle its color, and
+// Enter submits. None when Esc asks to type instead, or stdin isn't a terminal that
+// can be switched to raw mode
+pub fn pick_results(guess: &str) -> Option<String> {
+    use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+    use crossterm::{cursor, execute};
+    use std::io::{IsTerminal, Write};
+
+    if !io::stdin().is_terminal() {
+        return None;
+    }
+    let raw_mode = RawMode::enable()?;
+    // Raw mode doesn't return the cursor at a newline
+    print!("{}\r\n", messages::catalog().picker_help);
+    let length = guess.chars().count();
+    let mut colors = vec!['B'; length];
+    let mut position: usize = 0;
+    let picked = loop {
+        let row: String = colors.iter().collect();
+        print!("\r  {}", style::tiles(guess, &row));
+        // Park the cursor on the middle of the selected tile
+        let _ = execute!(io::stdout(), cursor::MoveToColumn((2 + position * 3 + 1) as u16));
+        let _ = io::stdout().flush();
+        let Ok(Event::Key(key)) = event::read() else { continue };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Enter => break Some(row),
+            KeyCode::Esc => break None,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break None,
+            KeyCode::Left => position = position.saturating_sub(1),
+            KeyCode::Right => position = (position + 1).min(length - 1),
+            KeyCode::Char(' ') | KeyCode::Up | KeyCode::Down => {
+                colors[position] = match colors[position] {
+                    'B' => 'Y',
+                    'Y' => 'G',
+                    _ => 'B',
+                };
+            }
+            KeyCode::Char(letter) if matches!(letter.to_ascii_uppercase(), 'G' | 'Y' | 'B') => {
+                colors[position] = letter.to_ascii_uppercase();
+                position = (position + 1).min(length - 1);
+            }
+            _ => {}
+        }
+    };
+    drop(raw_mode);
+    println!();
+    picked
+}
+
+// Reads a line with the terminal's echo off, so the typed word never appears on
+// screen; reads normally when stdin isn't a terminal or raw mode isn't available.
+// None at the end of input or on Ctrl-C
+pub fn read_hidden_line() -> Option<String> {
+    use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+    use std::io::IsTerminal;
+
+    let raw_mode = io::stdin().is_terminal().then(RawMode::enable).flatten();
+    let Some(raw_mode) = raw_mode else {
+        let mut input = String::new();
+        return match io::stdin().read_line(&mut input).expect("Failed to read line") {
+            0 => None,
+            _ => Some(input),
+        };
+    };
+    let mut input = String::new();
+    let finished = loop {
+        let Ok(Event::Key(key)) = event::read() else { continue };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Enter => break true,
+            KeyCode::Char('c') | KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => break false,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(letter) => input.push(letter),
+            _ => {}
+        }
+    };
+    drop(raw_mode);
+    println!();
+    finished.then_some(input)
+}
+
+// The terminal in raw mode, put back to normal when dropped so an early return or a
+// panic doesn't leave it without echo
+pub struct RawMode;
+
+impl RawMode {
+    // None when the terminal can't be switched, so the caller reads a plain line instead
+    fn enable() -> Option<Self> {
+        crossterm::terminal::enable_raw_mode().ok().map(|()| RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = crossterm::terminal::disable_raw_mode();
+    }
+}
+
+// The argument after a case-insensitive command word, like the file in 'SAVE game.txt'
+pub fn command_argument<'a>(input: &'a str, command: &str) -> Option<&'a str> {
+    let (word, argument) = input.split_once(' ')?;
+    let argument = argument.trim();
+    (word.eq_ignore_ascii_case(command) && !argument.is_empty()).then_some(argument)
+}
+
+// The next line typed in a game, trimmed, with a command in the prompt language read
+// as its English keyword. None at the end of input or on EXIT
+pub fn read_command() -> Option<String> {
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input).expect("Failed to read line") == 0 {
+        return None;
+    }
+    let input = messages::resolve_command(input.trim());
+    (!input.eq_ignore_ascii_case("EXIT")).then_some(input)
+}
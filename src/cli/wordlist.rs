@@ -0,0 +1,212 @@
+//! The `wordlist` subcommands, which check and clean up a word list file.
+
+use std::collections::{HashMap, HashSet};
+use solver_project::load::{audit_word_list, format_word_list};
+use solver_project::WordleSolver;
+use super::messages::{self, fill};
+use super::args::WordlistAction;
+
+// Carries out a wordlist subcommand, returning false on any problem found
+pub fn run_wordlist_action(action: &WordlistAction, length: usize, json: bool) -> bool {
+    let messages = messages::catalog();
+    let audit = |path: &str| match audit_word_list(path, length) {
+        Ok(audit) => Some(audit),
+        Err(e) => {
+            eprintln!("{}", e);
+            None
+        }
+    };
+    let write = |words: &[(String, f64)], output: &Option<String>| match output {
+        Some(path) => match std::fs::write(path, format_word_list(words)) {
+            Ok(()) => {
+                eprintln!("{}", fill(messages.wrote_words, &[&words.len(), path]));
+                true
+            }
+            Err(e) => {
+                eprintln!("{}", fill(messages.write_failed, &[path, &e]));
+                false
+            }
+        },
+        None => {
+            print!("{}", format_word_list(words));
+            true
+        }
+    };
+
+    match action {
+        WordlistAction::Validate { file } => {
+            let Some(audit) = audit(file) else { return false };
+            if json {
+                let issues: Vec<_> = audit
+                    .issues
+                    .iter()
+                    .map(|issue| serde_json::json!({ "line": issue.line, "problem": issue.problem.to_string(), "text": issue.text }))
+                    .collect();
+                println!("{}", serde_json::json!({ "file": file, "words": audit.words.len(), "issues": issues }));
+            } else {
+                for issue in &audit.issues {
+                    println!("{}:{}: {}: {}", file, issue.line, issue.problem, issue.text);
+                }
+            }
+            eprintln!("{}", fill(messages.validate_summary, &[&audit.words.len(), &audit.issues.len()]));
+            audit.issues.is_empty()
+        }
+        WordlistAction::Dedupe { file, output } => match audit(file) {
+            Some(audit) => write(&audit.words, output),
+            None => false,
+        },
+        WordlistAction::Merge { files, output } => {
+            let mut seen = HashSet::new();
+            let mut merged = Vec::new();
+            for file in files {
+                let Some(audit) = audit(file) else { return false };
+                merged.extend(audit.words.into_iter().filter(|(word, _)| seen.insert(word.clone())));
+            }
+            write(&merged, output)
+        }
+        WordlistAction::Diff { old, new } => {
+            let (Some(old), Some(new)) = (audit(old), audit(new)) else { return false };
+            let old_words: HashSet<&str> = old.words.iter().map(|(word, _)| word.as_str()).collect();
+            let new_words: HashSet<&str> = new.words.iter().map(|(word, _)| word.as_str()).collect();
+            let mut changes: Vec<(&str, char)> = old_words
+                .difference(&new_words)
+                .map(|&word| (word, '-'))
+                .chain(new_words.difference(&old_words).map(|&word| (word, '+')))
+                .collect();
+            changes.sort();
+            if json {
+                let words = |sign: char| changes.iter().filter(|change| change.1 == sign).map(|change| change.0).collect::<Vec<_>>();
+                println!("{}", serde_json::json!({ "removed": words('-'), "added": words('+') }));
+            } else {
+                for (word, sign) in &changes {
+                    println!("{} {}", sign, word);
+                }
+            }
+            changes.is_empty()
+        }
+        WordlistAction::Stats { file, top } => {
+            let Some(audit) = audit(file) else { return false };
+            let counts = LetterCounts::of(&audit.words, length);
+            if !json {
+                print_word_list_stats(&counts, audit.words.len(), length);
+                println!();
+            }
+            // Scored with the solver so the list's weights and the thread pool are used
+            match WordleSolver::builder(file).word_length(length).build() {
+                Ok(solver) => {
+                    let scores = solver.scored_guesses();
+                    let openers = scores.iter().take(*top);
+                    if json {
+                        let letters: Vec<_> = counts
+                            .letters()
+                            .into_iter()
+                            .map(|letter| {
+                                serde_json::json!({
+                                    "letter": ((b'a' + letter as u8) as char).to_string(),
+                                    "total": counts.overall[letter],
+                                    "by_position": counts.by_position.iter().map(|position| position[letter]).collect::<Vec<_>>(),
+                                })
+                            })
+                            .collect();
+                        let openers: Vec<_> = openers
+                            .map(|score| serde_json::json!({ "guess": score.word, "entropy": score.entropy, "worst_case": score.worst_case }))
+                            .collect();
+                        let repeats: Vec<_> = counts
+                            .repeats()
+                            .into_iter()
+                            .map(|(most, count)| serde_json::json!({ "copies": most, "words": count }))
+                            .collect();
+                        println!(
+                            "{}",
+                            serde_json::json!({
+                                "words": audit.words.len(),
+                                "length": length,
+                                "repeats": repeats,
+                                "letters": letters,
+                                "openers": openers,
+                            })
+                        );
+                    } else {
+                        println!("{}", messages.top_openers);
+                        for score in openers {
+                            let entropy = format!("{:.3}", score.entropy);
+                            println!("{}", fill(messages.opener_row, &[&score.word, &entropy, &score.worst_case]));
+                        }
+                    }
+                    true
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    false
+                }
+            }
+        }
+    }
+}
+
+// How often each letter turns up in a word list, overall and by position, and how many
+// words hold each number of copies of their most repeated letter
+pub struct LetterCounts {
+    overall: [usize; 26],
+    by_position: Vec<[usize; 26]>,
+    by_most_repeated: HashMap<usize, usize>,
+}
+
+impl LetterCounts {
+    fn of(words: &[(String, f64)], length: usize) -> Self {
+        let mut counts = Self { overall: [0; 26], by_position: vec![[0; 26]; length], by_most_repeated: HashMap::new() };
+        for (word, _) in words {
+            let mut copies = [0usize; 26];
+            for (idx, byte) in word.bytes().enumerate() {
+                let letter = (byte - b'a') as usize;
+                counts.overall[letter] += 1;
+                counts.by_position[idx][letter] += 1;
+                copies[letter] += 1;
+            }
+            *counts.by_most_repeated.entry(copies.iter().copied().max().unwrap_or(0)).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    // Letters that turn up at all, most frequent first
+    fn letters(&self) -> Vec<usize> {
+        let mut letters: Vec<usize> = (0..26).filter(|&letter| self.overall[letter] > 0).collect();
+        letters.sort_by_key(|&letter| std::cmp::Reverse(self.overall[letter]));
+        letters
+    }
+
+    // Words by the most copies of any one letter, for words repeating a letter
+    fn repeats(&self) -> Vec<(usize, usize)> {
+        let mut repeats: Vec<_> = self.by_most_repeated.iter().map(|(&most, &count)| (most, count)).filter(|&(most, _)| most > 1).collect();
+        repeats.sort();
+        repeats
+    }
+}
+
+// Prints the word count, letter frequencies overall and by position, and how many
+// words repeat a letter
+pub fn print_word_list_stats(counts: &LetterCounts, words: usize, length: usize) {
+    let messages = messages::catalog();
+    println!("{}", fill(messages.list_summary, &[&words, &length]));
+    let share = |count: usize| 100.0 * count as f64 / words.max(1) as f64;
+    let repeats = counts.repeats();
+    let repeated: usize = repeats.iter().map(|&(_, count)| count).sum();
+    println!("{}", fill(messages.repeat_summary, &[&repeated, &format!("{:.1}", share(repeated))]));
+    for (most, count) in repeats {
+        println!("{}", fill(messages.repeat_row, &[&count, &most]));
+    }
+
+    println!();
+    print!("{:<8}{:>7}", messages.heading_letter, messages.heading_total);
+    for idx in 1..=length {
+        print!("{:>6}", idx);
+    }
+    println!();
+    for letter in counts.letters() {
+        print!("{:<8}{:>7}", (b'a' + letter as u8) as char, counts.overall[letter]);
+        for position in &counts.by_position {
+            print!("{:>6}", position[letter]);
+        }
+        println!();
+    }
+}
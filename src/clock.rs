@@ -0,0 +1,179 @@
+//! Where the solver gets the current time, so anything time-stamped can be pinned down.
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// A source of the current time.
+pub trait Clock: fmt::Debug + Send + Sync {
+    fn now(&self) -> SystemTime;
+}
+
+/// The system's wall clock, used unless another clock is supplied.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock stopped at one instant, for reproducible timestamps.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedClock(pub SystemTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+/// Whole days since 1970-01-01 at `time`, as seen `utc_offset` seconds east of UTC.
+/// Anything counted by calendar day should go through this so every machine agrees
+/// on the day given the same offset.
+pub fn day_number(time: SystemTime, utc_offset: i64) -> i64 {
+    let seconds = match time.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() as i64,
+        Err(before) => {
+            // Part of a second before the epoch belongs to the second before it
+            let before = before.duration();
+            -(before.as_secs() as i64) - i64::from(before.subsec_nanos() > 0)
+        }
+    };
+    (seconds + utc_offset).div_euclid(SECONDS_PER_DAY)
+}
+
+/// The day number of a calendar date, counted like [`day_number`].
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    // Counts from March so the leap day falls at the end of the year
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The calendar date of a day number, as year, month and day.
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Reads a `YYYY-MM-DD` date as a day number.
+pub fn parse_date(date: &str) -> Option<i64> {
+    let mut fields = date.trim().splitn(3, '-');
+    let year = fields.next()?.parse::<i64>().ok()?;
+    let month = fields.next()?.parse::<u32>().ok().filter(|month| (1..=12).contains(month))?;
+    let day = fields.next()?.parse::<u32>().ok().filter(|day| *day >= 1)?;
+    let days = days_from_civil(year, month, day);
+    // Rejects days past the end of the month, which roll into the next one
+    (civil_from_days(days) == (year, month, day)).then_some(days)
+}
+
+/// Writes a day number as a `YYYY-MM-DD` date.
+pub fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Reads a UTC offset such as `+05:30`, `-0800` or `Z` as seconds east of UTC.
+pub fn parse_utc_offset(offset: &str) -> Option<i64> {
+    let offset = offset.trim();
+    if offset.eq_ignore_ascii_case("z") || offset.eq_ignore_ascii_case("utc") {
+        return Some(0);
+    }
+    let (sign, rest) = match offset.split_at_checked(1)? {
+        ("+", rest) => (1, rest),
+        ("-", rest) => (-1, rest),
+        _ => return None,
+    };
+    let digits: String = rest.chars().filter(|&c| c != ':').collect();
+    // Only ASCII digits, so the byte slicing below stays on character boundaries
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        1 | 2 => (digits.parse::<i64>().ok()?, 0),
+        4 => (digits[..2].parse::<i64>().ok()?, digits[2..].parse::<i64>().ok()?),
+        _ => return None,
+    };
+    (hours <= 14 && minutes < 60).then_some(sign * (hours * 3600 + minutes * 60))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn days_turn_over_at_midnight_on_either_side_of_the_epoch() {
+        let day = Duration::from_secs(SECONDS_PER_DAY as u64);
+        assert_eq!(day_number(UNIX_EPOCH, 0), 0);
+        assert_eq!(day_number(UNIX_EPOCH + day - Duration::from_nanos(1), 0), 0);
+        assert_eq!(day_number(UNIX_EPOCH + day, 0), 1);
+        // Just before midnight at the epoch, down to the nanosecond, is the day before
+        assert_eq!(day_number(UNIX_EPOCH - Duration::from_nanos(1), 0), -1);
+        assert_eq!(day_number(UNIX_EPOCH - Duration::from_millis(500), 0), -1);
+        assert_eq!(day_number(UNIX_EPOCH - day, 0), -1);
+        assert_eq!(day_number(UNIX_EPOCH - day - Duration::from_millis(1), 0), -2);
+        // East of UTC the day starts earlier
+        assert_eq!(day_number(UNIX_EPOCH - Duration::from_secs(60 * 60), 2 * 60 * 60), 0);
+    }
+
+    #[test]
+    fn dates_round_trip_through_day_numbers() {
+        assert_eq!(parse_date("1970-01-01"), Some(0));
+        assert_eq!(parse_date("2026-10-16"), Some(20742));
+        for date in ["1969-12-31", "2000-02-29", "2024-02-29", "2026-10-16", "2100-03-01"] {
+            let day = parse_date(date).unwrap();
+            assert_eq!(format_date(day), date);
+        }
+        for day in -800..800 {
+            let (year, month, date) = civil_from_days(day * 97);
+            assert_eq!(days_from_civil(year, month, date), day * 97);
+        }
+    }
+
+    #[test]
+    fn impossible_dates_are_rejected() {
+        for date in ["2023-02-29", "1900-02-29", "2026-04-31", "2026-13-01", "2026-00-10", "2026-10-00", "2026-10", "someday"] {
+            assert_eq!(parse_date(date), None, "{}", date);
+        }
+    }
+
+    #[test]
+    fn utc_offsets_read_in_each_form() {
+        assert_eq!(parse_utc_offset("+05:30"), Some(5 * 3600 + 30 * 60));
+        assert_eq!(parse_utc_offset("-0800"), Some(-8 * 3600));
+        assert_eq!(parse_utc_offset("+9"), Some(9 * 3600));
+        assert_eq!(parse_utc_offset("UTC"), Some(0));
+        assert_eq!(parse_utc_offset("z"), Some(0));
+        for offset in ["05:30", "+15", "-08:60", "+123", "", "++5", "+1é2", "-é:00", "+٠٥"] {
+            assert_eq!(parse_utc_offset(offset), None, "{}", offset);
+        }
+    }
+
+    #[test]
+    fn day_boundary_moves_with_the_offset() {
+        // 2026-10-16 20:00 UTC is already the 17th in India but still the 16th in California
+        let clock = FixedClock(UNIX_EPOCH + Duration::from_secs(20742 * 86400 + 20 * 3600));
+        let day = |offset: &str| format_date(day_number(clock.now(), parse_utc_offset(offset).unwrap()));
+        assert_eq!(day("UTC"), "2026-10-16");
+        assert_eq!(day("+05:30"), "2026-10-17");
+        assert_eq!(day("-0800"), "2026-10-16");
+        // Times before the epoch still land on the right day
+        assert_eq!(day_number(UNIX_EPOCH - Duration::from_secs(1), 0), -1);
+    }
+}
@@ -0,0 +1,213 @@
+//! What the feedback so far says about the answer, in a form that can be read back.
+
+use std::collections::{BTreeMap, BTreeSet};
+use crate::error::Result;
+use crate::load::Word;
+use crate::oracle::Feedback;
+
+/// What the feedback so far says about one letter, as shown on a keyboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LetterState {
+    /// Not guessed yet
+    Unused,
+    /// Not in the answer
+    Absent,
+    /// In the answer, position not yet known
+    Present,
+    /// Confirmed at one position or more
+    Correct,
+}
+
+/// Everything the feedback so far has established about the answer.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Constraints {
+    /// The letter confirmed at each position, if any
+    pub greens: Vec<Option<char>>,
+    /// Positions each letter is known not to occupy
+    pub banned_positions: BTreeMap<char, BTreeSet<usize>>,
+    /// Fewest copies of each letter the answer can hold
+    pub min_counts: BTreeMap<char, usize>,
+    /// Most copies of each letter the answer can hold, where a gray tile has capped it
+    pub max_counts: BTreeMap<char, usize>,
+}
+
+impl Constraints {
+    /// No knowledge yet about a word of `length` letters.
+    pub fn new(length: usize) -> Self {
+        Self { greens: vec![None; length], ..Self::default() }
+    }
+
+    /// Builds the constraints from a history of guesses and their feedback, failing on
+    /// the first turn [`Constraints::add_feedback`] refuses.
+    pub fn from_turns<'a>(length: usize, turns: impl IntoIterator<Item = (&'a str, &'a str)>) -> Result<Self> {
+        let mut constraints = Self::new(length);
+        for (guess, results) in turns {
+            constraints.add_feedback(guess, results)?;
+        }
+        Ok(constraints)
+    }
+
+    /// Folds in the feedback `results` for `guess`. Both must be as long as the words
+    /// these constraints describe; anything else is an [`Error::InvalidWord`] or
+    /// [`Error::InvalidResults`] and changes nothing.
+    ///
+    /// [`Error::InvalidWord`]: crate::Error::InvalidWord
+    /// [`Error::InvalidResults`]: crate::Error::InvalidResults
+    pub fn add_feedback(&mut self, guess: &str, results: &str) -> Result<()> {
+        let guess = Word::parse(guess, self.greens.len())?;
+        let results = Feedback::parse(results, self.greens.len())?;
+        // Copies of each letter the feedback marked present, and whether any copy came back gray
+        let mut marked: BTreeMap<char, (usize, bool)> = BTreeMap::new();
+        for (idx, (letter, status)) in guess.as_str().chars().zip(results.as_str().chars()).enumerate() {
+            let entry = marked.entry(letter).or_default();
+            match status {
+                'G' => {
+                    self.greens[idx] = Some(letter);
+                    entry.0 += 1;
+                }
+                'Y' => {
+                    self.banned_positions.entry(letter).or_default().insert(idx);
+                    entry.0 += 1;
+                }
+                _ => {
+                    self.banned_positions.entry(letter).or_default().insert(idx);
+                    entry.1 = true;
+                }
+            }
+        }
+
+        for (letter, (present, capped)) in marked {
+            if present > 0 {
+                let min = self.min_counts.entry(letter).or_insert(0);
+                *min = (*min).max(present);
+            }
+            if capped {
+                let max = self.max_counts.entry(letter).or_insert(present);
+                *max = (*max).min(present);
+            }
+        }
+        Ok(())
+    }
+
+    /// Letters the answer can't contain at all.
+    pub fn excluded(&self) -> BTreeSet<char> {
+        self.max_counts.iter().filter(|&(_, &max)| max == 0).map(|(&letter, _)| letter).collect()
+    }
+
+    /// Letters known to be in the answer, each with the positions it can't occupy.
+    /// Positions taken by a green are left out.
+    pub fn yellows(&self) -> BTreeMap<char, BTreeSet<usize>> {
+        self.min_counts
+            .keys()
+            .map(|&letter| {
+                let banned = self.banned_positions.get(&letter).cloned().unwrap_or_default();
+                let banned = banned.into_iter().filter(|&idx| self.greens[idx].is_none()).collect();
+                (letter, banned)
+            })
+            .collect()
+    }
+
+    /// The best-known state of `letter`: green beats yellow beats gray.
+    pub fn letter_state(&self, letter: char) -> LetterState {
+        if self.greens.contains(&Some(letter)) {
+            LetterState::Correct
+        } else if self.min_counts.contains_key(&letter) {
+            LetterState::Present
+        } else if self.max_counts.contains_key(&letter) {
+            LetterState::Absent
+        } else {
+            LetterState::Unused
+        }
+    }
+
+    /// Whether `word` agrees with every piece of feedback so far.
+    pub fn allows(&self, word: &str) -> bool {
+        if word.chars().count() != self.greens.len() {
+            return false;
+        }
+        let positions_allowed = word.chars().enumerate().all(|(idx, letter)| {
+            self.banned_positions.get(&letter).is_none_or(|banned| !banned.contains(&idx))
+        });
+        self.greens_kept(word) && positions_allowed && self.counts_allowed(word, true)
+    }
+
+    /// Whether `word` is a legal hard-mode guess: every green kept in place and
+    /// every revealed letter reused.
+    pub fn allows_hard_mode_guess(&self, word: &str) -> bool {
+        self.greens_kept(word) && self.counts_allowed(word, false)
+    }
+
+    /// The first hard-mode rule `word` breaks, described for the player, or `None`
+    /// if it is a legal hard-mode guess.
+    pub fn hard_mode_violation(&self, word: &str) -> Option<String> {
+        for (idx, (green, letter)) in self.greens.iter().zip(word.chars()).enumerate() {
+            if let Some(green) = green.filter(|&green| green != letter) {
+                return Some(format!("letter {} must be {}", idx + 1, green.to_ascii_uppercase()));
+            }
+        }
+        self.min_counts.iter().find_map(|(&letter, &min)| {
+            let count = word.chars().filter(|&l| l == letter).count();
+            (count < min).then(|| match min {
+                1 => format!("guess must contain {}", letter.to_ascii_uppercase()),
+                _ => format!("guess must contain {} {} times", letter.to_ascii_uppercase(), min),
+            })
+        })
+    }
+
+    // Checked letter by letter without collecting the word, since this runs for
+    // every candidate on every turn
+    fn greens_kept(&self, word: &str) -> bool {
+        self.greens.iter().zip(word.chars()).all(|(green, letter)| green.is_none_or(|g| g == letter))
+    }
+
+    fn counts_allowed(&self, word: &str, check_max: bool) -> bool {
+        let count = |letter: char| word.chars().filter(|&l| l == letter).count();
+        self.min_counts.iter().all(|(&letter, &min)| count(letter) >= min)
+            && (!check_max || self.max_counts.iter().all(|(&letter, &max)| count(letter) <= max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+    use crate::oracle::simulate_results;
+
+    const WORDS: [&str; 16] = [
+        "speed", "abide", "eerie", "there", "llama", "hello", "geese", "lever", "sheep", "eaten", "allay", "label",
+        "belle", "creed", "steel", "elder",
+    ];
+
+    // A word fits the feedback exactly when it would have produced that feedback as
+    // the answer, duplicates included
+    #[test]
+    fn allows_exactly_the_words_the_feedback_agrees_with() {
+        for guess in WORDS {
+            for secret in WORDS {
+                let results = simulate_results(guess, secret);
+                let mut constraints = Constraints::new(5);
+                constraints.add_feedback(guess, &results).unwrap();
+                for word in WORDS {
+                    assert_eq!(
+                        constraints.allows(word),
+                        simulate_results(guess, word) == results,
+                        "{} after {}={}",
+                        word,
+                        guess,
+                        results
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn feedback_of_the_wrong_length_is_refused() {
+        let mut constraints = Constraints::new(5);
+        assert!(matches!(constraints.add_feedback("speed", "GGBB"), Err(Error::InvalidResults { length: 5, .. })));
+        assert!(matches!(constraints.add_feedback("speeds", "GGBBBB"), Err(Error::InvalidWord { length: 5, .. })));
+        assert!(matches!(constraints.add_feedback("spe", "GGB"), Err(Error::InvalidWord { length: 5, .. })));
+        assert_eq!(constraints, Constraints::new(5));
+        assert!(Constraints::from_turns(5, [("speed", "GGBBB"), ("abide", "BB")]).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+//! Daily puzzles: which word a day's puzzle hides, picking a saved game back up, and
+//! counting the finished game in the player's stats.
+
+use std::path::Path;
+use std::time::Duration;
+use crate::clock::{format_date, parse_date};
+use crate::error::Result;
+use crate::game::{daily_secret, Difficulty, Game, SavedGame};
+use crate::solver::{GameStatus, WordleSolver};
+use crate::stats::{append_game, game_log_path, FinishedGame, PlayerStats};
+
+/// Which daily puzzle to play and under what rules.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DailySettings {
+    /// The puzzle's day, counted from 1970-01-01
+    pub day: i64,
+    /// The day of puzzle 0
+    pub epoch_day: i64,
+    /// Seeds the order the secrets come up in
+    pub rotation: u64,
+    pub hard_mode: bool,
+    pub difficulty: Difficulty,
+    /// Hints allowed per game, `None` for any number
+    pub hint_budget: Option<usize>,
+    /// Whether the game counts toward the ranked ledger instead of the casual stats
+    pub ranked: bool,
+}
+
+impl DailySettings {
+    /// The settings `saved` was played with, or `None` if its dates can't be read.
+    pub fn from_saved(saved: &SavedGame) -> Option<Self> {
+        Some(Self {
+            day: parse_date(&saved.date)?,
+            epoch_day: parse_date(&saved.epoch)?,
+            rotation: saved.rotation,
+            hard_mode: saved.hard_mode,
+            difficulty: saved.difficulty,
+            hint_budget: saved.hint_budget,
+            ranked: saved.ranked,
+        })
+    }
+
+    /// The puzzle number, counting from the epoch.
+    pub fn puzzle(&self) -> i64 {
+        self.day - self.epoch_day
+    }
+
+    /// Hints allowed in this game; ranked games get none.
+    pub fn hints_allowed(&self) -> Option<usize> {
+        if self.ranked {
+            Some(0)
+        } else {
+            self.hint_budget
+        }
+    }
+
+    /// The day's secret among the candidates of `solver` its difficulty allows, or
+    /// `None` if it allows none of them.
+    pub fn secret(&self, solver: &WordleSolver) -> Option<String> {
+        let pool = self.difficulty.secret_pool(solver.candidates(), solver.weights());
+        daily_secret(&pool, self.puzzle(), self.rotation).cloned()
+    }
+
+    /// A fresh game hiding `secret`, taking guesses from `solver`'s guess list.
+    pub fn new_game(&self, solver: &WordleSolver, secret: &str) -> Game {
+        let mut game = Game::new(secret, solver.allowed_guesses().iter().cloned(), solver.max_attempts);
+        game.set_hard_mode(self.hard_mode);
+        game
+    }
+
+    /// Plays the hints and guesses of `saved` into `game`, failing on the first guess
+    /// the game refuses.
+    pub fn resume(&self, game: &mut Game, saved: &SavedGame) -> Result<()> {
+        for _ in 0..saved.hints_used {
+            game.record_hint();
+        }
+        for guess in &saved.guesses {
+            game.guess(guess)?;
+        }
+        Ok(())
+    }
+
+    /// `game` as it stands, to be picked up again with [`DailySettings::resume`].
+    pub fn save(&self, game: &Game) -> SavedGame {
+        SavedGame {
+            date: format_date(self.day),
+            epoch: format_date(self.epoch_day),
+            rotation: self.rotation,
+            hard_mode: self.hard_mode,
+            difficulty: self.difficulty,
+            hints_used: game.hints_used(),
+            hint_budget: if self.ranked { None } else { self.hint_budget },
+            ranked: self.ranked,
+            guesses: game.turns().iter().map(|(guess, _)| guess.clone()).collect(),
+        }
+    }
+
+    /// Counts the finished `game` in the stats at `path`, with the solve `time` when it
+    /// was timed. Returns the updated stats and whether the time is a personal best.
+    pub fn record_stats(&self, path: &str, game: &Game, time: Option<Duration>) -> Result<(PlayerStats, bool)> {
+        let mut stats = PlayerStats::load(path)?;
+        let won = game.status() == GameStatus::Won;
+        stats.record(self.day, game.secret(), won.then(|| game.turns().len()));
+        stats.hints += game.hints_used();
+        let personal_best = time.is_some_and(|time| stats.record_time(time));
+        stats.save(path)?;
+        Ok((stats, personal_best))
+    }
+
+    /// Adds the finished `game` to the game log beside the stats at `path`.
+    pub fn log_game(&self, path: &str, game: &Game) -> Result<()> {
+        let finished = FinishedGame {
+            day: self.day,
+            secret: game.secret().to_string(),
+            guesses: game.turns().iter().map(|(guess, _)| guess.clone()).collect(),
+        };
+        append_game(&game_log_path(Path::new(path)).to_string_lossy(), &finished)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{temp_path, words};
+
+    fn settings() -> DailySettings {
+        DailySettings {
+            day: parse_date("2026-10-16").unwrap(),
+            epoch_day: parse_date("2021-06-19").unwrap(),
+            rotation: 0,
+            hard_mode: false,
+            difficulty: Difficulty::Normal,
+            hint_budget: Some(3),
+            ranked: false,
+        }
+    }
+
+    #[test]
+    fn saved_games_resume_where_they_left_off() {
+        let solver = WordleSolver::builder_from(words(&["break", "bread", "cream", "dream", "crane", "slimy"])).build().unwrap();
+        let settings = settings();
+        let secret = settings.secret(&solver).unwrap();
+        let mut game = settings.new_game(&solver, &secret);
+        game.record_hint();
+        game.guess("slimy").unwrap();
+
+        let saved = settings.save(&game);
+        assert_eq!(DailySettings::from_saved(&saved), Some(settings));
+        let mut resumed = settings.new_game(&solver, &secret);
+        settings.resume(&mut resumed, &saved).unwrap();
+        assert_eq!(resumed.turns(), game.turns());
+        assert_eq!(resumed.hints_used(), 1);
+
+        // Ranked games allow no hints and don't save a budget
+        let ranked = DailySettings { ranked: true, ..settings };
+        assert_eq!(ranked.hints_allowed(), Some(0));
+        assert_eq!(ranked.save(&game).hint_budget, None);
+    }
+
+    #[test]
+    fn finished_games_are_counted_and_logged() {
+        let path = temp_path("daily-stats.txt");
+        let solver = WordleSolver::builder_from(words(&["break"])).build().unwrap();
+        let settings = settings();
+        let mut game = settings.new_game(&solver, "break");
+        game.guess("break").unwrap();
+
+        let (stats, personal_best) = settings.record_stats(&path, &game, Some(Duration::from_secs(30))).unwrap();
+        assert_eq!((stats.played, stats.won), (1, 1));
+        assert!(personal_best);
+        settings.log_game(&path, &game).unwrap();
+        let log = game_log_path(Path::new(&path)).to_string_lossy().into_owned();
+        let logged = crate::stats::load_games(&log).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&log).unwrap();
+        assert_eq!(logged, [FinishedGame { day: settings.day, secret: "break".to_string(), guesses: vec!["break".to_string()] }]);
+    }
+}
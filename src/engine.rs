@@ -0,0 +1,52 @@
+//! The interface front ends drive a game through, so one solver can be swapped for another.
+
+use crate::error::{Error, Result};
+use crate::load::Word;
+use crate::oracle::parse_results_code;
+use crate::solver::{GameStatus, WordleSolver};
+
+/// A game in progress that suggests guesses and narrows its candidates from feedback.
+///
+/// The trait is object safe, so front ends can hold a `Box<dyn SolverEngine>` and
+/// not care which engine is behind it.
+pub trait SolverEngine {
+    /// The guess to play next, or `None` once the game is over.
+    fn suggest(&self) -> Option<&str>;
+
+    /// Records that `guess` was played and got `results`, a string of 'G', 'Y' and 'B'.
+    /// In hard mode a guess that drops a revealed letter is refused, and once the
+    /// game is won or lost every guess is refused with [`Error::GameOver`].
+    fn apply(&mut self, guess: &str, results: &str) -> Result<GameStatus>;
+
+    /// Words still consistent with every result applied so far.
+    fn candidates(&self) -> &[String];
+
+    /// Where the game stands.
+    fn status(&self) -> GameStatus;
+}
+
+impl SolverEngine for WordleSolver {
+    fn suggest(&self) -> Option<&str> {
+        match WordleSolver::status(self) {
+            GameStatus::Ongoing => self.current_guess(),
+            _ => None,
+        }
+    }
+
+    fn apply(&mut self, guess: &str, results: &str) -> Result<GameStatus> {
+        if WordleSolver::status(self) != GameStatus::Ongoing {
+            return Err(Error::GameOver);
+        }
+        let guess = Word::parse(guess, self.word_length())?;
+        let code = parse_results_code(results, self.word_length())?;
+        self.play_turn(guess.as_str(), code, true)
+    }
+
+    fn candidates(&self) -> &[String] {
+        WordleSolver::candidates(self)
+    }
+
+    fn status(&self) -> GameStatus {
+        WordleSolver::status(self)
+    }
+}
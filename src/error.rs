@@ -0,0 +1,105 @@
+//! The error type shared by every fallible operation in the crate.
+
+use std::error;
+use std::fmt;
+use std::io;
+
+/// Everything that can go wrong loading word lists or driving a game.
+#[derive(Debug)]
+pub enum Error {
+    /// A word list couldn't be read
+    Io { path: String, source: io::Error },
+    /// A word list held no usable words of the requested length
+    NoWords { path: String, length: usize },
+    /// Solver settings that are out of range or can't be combined
+    InvalidSettings(String),
+    /// A saved session file that can't be parsed
+    InvalidSession { path: String, reason: String },
+    /// A player statistics file that can't be parsed
+    InvalidStats { path: String, reason: String },
+    /// A saved session that doesn't fit the solver it is resumed into
+    SessionMismatch(String),
+    /// A word that isn't all letters, or has the wrong length
+    InvalidWord { word: String, length: usize },
+    /// Feedback that isn't one 'G', 'Y' or 'B' per letter of the word
+    InvalidResults { results: String, length: usize },
+    /// A word list couldn't be downloaded, and no cached copy was available
+    Fetch { url: String, reason: String },
+    /// A guess that isn't in the game's word list
+    UnknownWord(String),
+    /// A guess made after the game was already won or lost
+    GameOver,
+    /// A hard-mode guess that drops a revealed letter, with the rule it broke
+    HardMode { word: String, rule: String },
+    /// A guess on several boards given a different number of results than boards still in play
+    ResultCount { results: usize, boards: usize },
+    /// A turn number, counting from 1, past the turns played so far
+    NoSuchTurn { turn: usize, turns: usize },
+    /// A turn of a history, counting from 1, that can't be played, and why
+    HistoryTurn { turn: usize, guess: String, results: String, conflict: TurnConflict },
+    /// A turn that can't be removed because a later turn no longer fits without it
+    TurnKept { turn: usize, guess: String, results: String, conflict: TurnConflict },
+}
+
+/// What goes wrong replaying a later turn once an earlier one is removed.
+#[derive(Debug)]
+pub enum TurnConflict {
+    /// The later feedback rules out every word
+    NoWordFits,
+    /// The later feedback would win or lose the game before its last turn, or lose it on that turn
+    GameOver,
+    /// The later turn is refused outright, e.g. by hard mode
+    Refused(Box<Error>),
+}
+
+/// Shorthand for results carrying an [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "couldn't read {}: {}", path, source),
+            Error::NoWords { path, length } => write!(f, "no {}-letter words found in {}", length, path),
+            Error::InvalidSettings(reason) => write!(f, "invalid settings: {}", reason),
+            Error::InvalidSession { path, reason } => write!(f, "{} is not a valid session: {}", path, reason),
+            Error::InvalidStats { path, reason } => write!(f, "{} is not a valid stats file: {}", path, reason),
+            Error::SessionMismatch(reason) => write!(f, "session doesn't match this game: {}", reason),
+            Error::InvalidWord { word, length } => write!(f, "'{}' is not a {}-letter word", word, length),
+            Error::InvalidResults { results, length } => {
+                write!(f, "'{}' is not a {}-letter string of 'G', 'Y', and 'B'", results, length)
+            }
+            Error::Fetch { url, reason } => write!(f, "couldn't download {}: {}", url, reason),
+            Error::UnknownWord(word) => write!(f, "'{}' is not in the word list", word),
+            Error::GameOver => write!(f, "the game is already over"),
+            Error::HardMode { word, rule } => write!(f, "'{}' breaks hard mode: {}", word, rule),
+            Error::ResultCount { results, boards } => write!(f, "{} results given for {} unsolved boards", results, boards),
+            Error::NoSuchTurn { turn, turns } => write!(f, "there is no turn {}; {} turns have been played", turn, turns),
+            Error::HistoryTurn { turn, guess, results, conflict: TurnConflict::GameOver } => {
+                write!(f, "turn {}, {}={}, comes after the game is over", turn, guess, results)
+            }
+            Error::HistoryTurn { turn, guess, results, conflict } => write!(f, "turn {}, {}={}, {}", turn, guess, results, conflict),
+            Error::TurnKept { turn, guess, results, conflict } => {
+                write!(f, "turn {} is needed: without it, {}={} {}", turn, guess, results, conflict)
+            }
+        }
+    }
+}
+
+impl fmt::Display for TurnConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TurnConflict::NoWordFits => write!(f, "leaves no word that fits"),
+            TurnConflict::GameOver => write!(f, "would end the game"),
+            TurnConflict::Refused(e) => write!(f, "is refused: {}", e),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
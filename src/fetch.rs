@@ -0,0 +1,161 @@
+//! Word lists downloaded from a URL and cached on disk, so a remote list is only
+//! fetched again once the cached copy is old and the server says it changed.
+
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+use crate::error::{Error, Result};
+use crate::load::{parse_word_list, WordSource};
+use crate::session::word_list_fingerprint;
+
+/// How long a cached list is used without asking the server whether it changed.
+pub const DEFAULT_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// A word list at an `http://` or `https://` URL, in the format read by
+/// [`load_word_list`](crate::load::load_word_list).
+///
+/// Downloads are kept in the user's cache directory together with the server's
+/// ETag. Once a copy is older than `max_age` the server is asked for the list again
+/// with that ETag, and the copy is reused if it hasn't changed. If the server can't
+/// be reached, any cached copy is used however old it is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UrlSource {
+    pub url: String,
+    pub max_age: Duration,
+}
+
+impl UrlSource {
+    pub fn new(url: &str) -> Self {
+        Self { url: url.to_string(), max_age: DEFAULT_MAX_AGE }
+    }
+
+    /// Whether `location` looks like something to fetch rather than a file path.
+    pub fn is_url(location: &str) -> bool {
+        location.starts_with("http://") || location.starts_with("https://")
+    }
+
+    /// Where the downloaded list is kept.
+    pub fn cache_path(&self) -> Option<PathBuf> {
+        let name = format!("{:016x}.txt", word_list_fingerprint(std::slice::from_ref(&self.url)));
+        cache_dir().map(|dir| dir.join(name))
+    }
+
+    // The list's text, from the cache when it is fresh enough and from the server otherwise
+    fn text(&self) -> Result<String> {
+        let fetch_error = |reason: String| Error::Fetch { url: self.url.clone(), reason };
+        let cache_path = self.cache_path();
+        let etag_path = cache_path.as_ref().map(|path| path.with_extension("etag"));
+        let cached = cache_path.as_ref().and_then(|path| fs::read_to_string(path).ok());
+        let age = cache_path
+            .as_ref()
+            .and_then(|path| fs::metadata(path).ok()?.modified().ok())
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+        if let (Some(text), Some(age)) = (&cached, age) {
+            if age < self.max_age {
+                return Ok(text.clone());
+            }
+        }
+
+        let mut request = ureq::get(&self.url);
+        if let Some(etag) = cached.as_ref().and(etag_path.as_ref()).and_then(|path| fs::read_to_string(path).ok()) {
+            request = request.set("If-None-Match", etag.trim());
+        }
+        let response = match request.call() {
+            Ok(response) => response,
+            Err(e) => return cached.ok_or_else(|| fetch_error(describe(e))),
+        };
+        if response.status() == 304 {
+            let text = cached.ok_or_else(|| fetch_error("server sent no list".to_string()))?;
+            // Rewrite the copy so its age counts from this check
+            if let Some(path) = &cache_path {
+                let _ = fs::write(path, &text);
+            }
+            return Ok(text);
+        }
+
+        let etag = response.header("ETag").map(str::to_string);
+        let text = response.into_string().map_err(|e| fetch_error(e.to_string()))?;
+        if let (Some(path), Some(etag_path)) = (&cache_path, &etag_path) {
+            // Caching is best effort; the download is usable either way
+            let _ = path.parent().map(fs::create_dir_all);
+            let _ = fs::write(path, &text);
+            let _ = match &etag {
+                Some(etag) => fs::write(etag_path, etag),
+                None => fs::remove_file(etag_path),
+            };
+        }
+        Ok(text)
+    }
+}
+
+impl WordSource for UrlSource {
+    fn name(&self) -> String {
+        self.url.clone()
+    }
+
+    fn words(&self, length: usize) -> Result<Vec<(String, f64)>> {
+        let text = self.text()?;
+        if text.trim_start().starts_with('<') {
+            return Err(Error::Fetch { url: self.url.clone(), reason: "got a web page, not a word list".to_string() });
+        }
+        Ok(parse_word_list(text.as_bytes(), length))
+    }
+}
+
+// What went wrong with a request, without repeating the URL
+fn describe(error: ureq::Error) -> String {
+    match error {
+        ureq::Error::Status(code, _) => format!("the server answered with status {}", code),
+        ureq::Error::Transport(transport) => {
+            let mut reason = transport.kind().to_string();
+            if let Some(message) = transport.message() {
+                reason.push_str(&format!(": {}", message));
+            }
+            if let Some(source) = std::error::Error::source(&transport) {
+                reason.push_str(&format!(": {}", source));
+            }
+            reason
+        }
+    }
+}
+
+// The per-user cache directory for this program
+fn cache_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+    Some(base.join("wordle-solver"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::temp_path;
+
+    #[test]
+    fn cached_lists_stand_in_for_the_server() {
+        std::env::set_var("XDG_CACHE_HOME", temp_path("cache"));
+        // Nothing listens on this port, so every request fails
+        let mut source = UrlSource::new("http://127.0.0.1:9/words.txt");
+        let cache_path = source.cache_path().unwrap();
+        fs::create_dir_all(cache_path.parent().unwrap()).unwrap();
+
+        fs::write(&cache_path, "crane\nslate\t2\n").unwrap();
+        let fresh = source.words(5);
+        // A stale copy is still used when the server can't be reached
+        source.max_age = Duration::ZERO;
+        let stale = source.words(5);
+        fs::write(&cache_path, "<!doctype html>\n").unwrap();
+        let page = source.words(5);
+        fs::remove_file(&cache_path).unwrap();
+        let missing = source.words(5);
+
+        let expected = [("crane".to_string(), 1.0), ("slate".to_string(), 2.0)];
+        assert_eq!(fresh.unwrap(), expected);
+        assert_eq!(stale.unwrap(), expected);
+        assert!(matches!(page, Err(Error::Fetch { .. })));
+        assert!(matches!(missing, Err(Error::Fetch { .. })));
+        assert!(UrlSource::is_url("https://example.com/words.txt") && !UrlSource::is_url("words.txt"));
+    }
+}
@@ -0,0 +1,559 @@
+//! Games where a person guesses a hidden word and gets the same feedback the solver reads.
+
+use std::collections::HashSet;
+use std::fs;
+use crate::constraints::Constraints;
+use crate::error::{Error, Result};
+use crate::load::{common_word_weights, Word};
+use crate::oracle::{word_weight, Feedback, WordWeights};
+use crate::solver::GameStatus;
+
+const SAVED_HEADER: &str = "wordle-solver daily game";
+
+/// Day number of 2021-06-19, the day the original puzzle numbers count from.
+pub const DAILY_EPOCH: i64 = 18797;
+
+/// A game against a hidden secret, checking each guess against a list of allowed words.
+#[derive(Clone, Debug)]
+pub struct Game {
+    secret: String,
+    allowed: HashSet<String>,
+    max_attempts: usize,
+    hard_mode: bool,
+    constraints: Constraints,
+    turns: Vec<(String, Feedback)>,
+    hints_used: usize,
+    given_up: bool,
+}
+
+impl Game {
+    /// Starts a game whose guesses must come from `allowed`; the secret is always allowed.
+    pub fn new(secret: &str, allowed: impl IntoIterator<Item = String>, max_attempts: usize) -> Self {
+        let mut allowed: HashSet<String> = allowed.into_iter().collect();
+        allowed.insert(secret.to_string());
+        Self {
+            secret: secret.to_string(),
+            allowed,
+            max_attempts,
+            hard_mode: false,
+            constraints: Constraints::new(secret.len()),
+            turns: Vec::new(),
+            hints_used: 0,
+            given_up: false,
+        }
+    }
+
+    /// Makes every later guess keep the greens in place and reuse the yellows.
+    pub fn set_hard_mode(&mut self, hard_mode: bool) {
+        self.hard_mode = hard_mode;
+    }
+
+    /// Plays `word` and returns its feedback.
+    pub fn guess(&mut self, word: &str) -> Result<&Feedback> {
+        if self.status() != GameStatus::Ongoing {
+            return Err(Error::GameOver);
+        }
+        let word = Word::parse(word, self.word_length())?.into_string();
+        if !self.allowed.contains(&word) {
+            return Err(Error::UnknownWord(word));
+        }
+        if self.hard_mode {
+            if let Some(rule) = self.constraints.hard_mode_violation(&word) {
+                return Err(Error::HardMode { word, rule });
+            }
+        }
+        let feedback = Feedback::simulate(&word, &self.secret);
+        self.constraints.add_feedback(&word, feedback.as_str()).expect("a checked word and its own feedback");
+        self.turns.push((word, feedback));
+        Ok(&self.turns.last().unwrap().1)
+    }
+
+    /// Ends the game as a loss, if it isn't over already.
+    pub fn give_up(&mut self) {
+        if self.status() == GameStatus::Ongoing {
+            self.given_up = true;
+        }
+    }
+
+    /// Whether the game ended because the player gave up.
+    pub fn given_up(&self) -> bool {
+        self.given_up
+    }
+
+    pub fn status(&self) -> GameStatus {
+        match self.turns.last() {
+            _ if self.given_up => GameStatus::Lost,
+            Some((_, feedback)) if feedback.is_solved() => GameStatus::Won,
+            _ if self.turns.len() >= self.max_attempts => GameStatus::Lost,
+            _ => GameStatus::Ongoing,
+        }
+    }
+
+    /// Every guess played so far with its feedback, oldest first.
+    pub fn turns(&self) -> &[(String, Feedback)] {
+        &self.turns
+    }
+
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    pub fn word_length(&self) -> usize {
+        self.secret.len()
+    }
+
+    pub fn max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    pub fn hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    /// Counts a hint taken during this game.
+    pub fn record_hint(&mut self) {
+        self.hints_used += 1;
+    }
+
+    pub fn hints_used(&self) -> usize {
+        self.hints_used
+    }
+
+    /// What the feedback so far has established about the secret.
+    pub fn constraints(&self) -> &Constraints {
+        &self.constraints
+    }
+
+    /// The spoiler-free share block: a title line like `Wordle-solver #1945 4/6`
+    /// (`X/6` for a loss, `?/6` while still playing, `*` after it in hard mode, and
+    /// the number of hints taken if any), then one row of squares per guess in the
+    /// colors of `palette`.
+    pub fn share_grid(&self, puzzle: i64, palette: Palette) -> String {
+        let score = match self.status() {
+            GameStatus::Won => self.turns.len().to_string(),
+            GameStatus::Lost => "X".to_string(),
+            GameStatus::Ongoing => "?".to_string(),
+        };
+        let mut text = format!(
+            "Wordle-solver #{} {}/{}{}",
+            puzzle,
+            score,
+            self.max_attempts,
+            if self.hard_mode { "*" } else { "" }
+        );
+        match self.hints_used {
+            0 => {}
+            1 => text.push_str(" (1 hint)"),
+            hints => text.push_str(&format!(" ({} hints)", hints)),
+        }
+        text.push('\n');
+        for (_, feedback) in &self.turns {
+            text.push('\n');
+            text.extend(feedback.as_str().chars().map(|status| match (palette, status) {
+                (Palette::Standard, 'G') => '🟩',
+                (Palette::Standard, 'Y') => '🟨',
+                (Palette::ColorBlind, 'G') => '🟧',
+                (Palette::ColorBlind, 'Y') => '🟦',
+                _ => '⬛',
+            }));
+        }
+        text
+    }
+}
+
+/// Several games against different secrets played with the same guesses, as in
+/// Dordle and Quordle. A board locks once solved, and the game is won when every
+/// board is.
+#[derive(Clone, Debug)]
+pub struct MultiGame {
+    boards: Vec<Game>,
+    max_attempts: usize,
+    guesses: usize,
+}
+
+impl MultiGame {
+    /// Starts one board per secret, all allowing `max_attempts` guesses.
+    pub fn new(secrets: &[String], allowed: &[String], max_attempts: usize) -> Self {
+        let boards = secrets.iter().map(|secret| Game::new(secret, allowed.iter().cloned(), max_attempts)).collect();
+        Self { boards, max_attempts, guesses: 0 }
+    }
+
+    /// Plays `word` on every board not yet solved.
+    pub fn guess(&mut self, word: &str) -> Result<()> {
+        if self.status() != GameStatus::Ongoing {
+            return Err(Error::GameOver);
+        }
+        // Checked on one open board first so a bad word changes nothing
+        let mut open = self.boards.iter_mut().filter(|board| board.status() == GameStatus::Ongoing);
+        if let Some(first) = open.next() {
+            first.guess(word)?;
+        }
+        for board in open {
+            board.guess(word)?;
+        }
+        self.guesses += 1;
+        Ok(())
+    }
+
+    pub fn status(&self) -> GameStatus {
+        if self.boards.iter().all(|board| board.status() == GameStatus::Won) {
+            GameStatus::Won
+        } else if self.guesses >= self.max_attempts {
+            GameStatus::Lost
+        } else {
+            GameStatus::Ongoing
+        }
+    }
+
+    pub fn boards(&self) -> &[Game] {
+        &self.boards
+    }
+
+    /// Guesses played so far.
+    pub fn guesses(&self) -> usize {
+        self.guesses
+    }
+
+    pub fn max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+}
+
+/// Colors for correct and misplaced letters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Palette {
+    /// Green and yellow
+    #[default]
+    Standard,
+    /// Orange and blue, told apart with the common forms of color blindness
+    ColorBlind,
+}
+
+impl Palette {
+    pub fn label(self) -> &'static str {
+        match self {
+            Palette::Standard => "standard",
+            Palette::ColorBlind => "color-blind",
+        }
+    }
+}
+
+/// How hard a game's secret tends to be.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Difficulty {
+    /// Only common words
+    Easy,
+    /// Any word in the list
+    #[default]
+    Normal,
+    /// Uncommon words and words that repeat a letter
+    Hard,
+}
+
+impl Difficulty {
+    pub fn label(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+        }
+    }
+
+    /// Reads a name written by [`Difficulty::label`].
+    pub fn from_label(label: &str) -> Option<Self> {
+        [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard].into_iter().find(|difficulty| difficulty.label() == label)
+    }
+
+    /// The words of `words` this difficulty draws secrets from. A word counts as
+    /// common when it is on the built-in common word list or its list weight is
+    /// above 1. Falls back to every word if the filter would leave none.
+    pub fn secret_pool(self, words: &[String], weights: &WordWeights) -> Vec<String> {
+        let common_words = common_word_weights();
+        let common = |word: &str| common_words.contains_key(word) || word_weight(weights, word) > 1.0;
+        let repeats_letter = |word: &str| word.chars().enumerate().any(|(idx, letter)| word[idx + 1..].contains(letter));
+        let pool: Vec<String> = words
+            .iter()
+            .filter(|word| match self {
+                Difficulty::Easy => common(word),
+                Difficulty::Normal => true,
+                Difficulty::Hard => !common(word) || repeats_letter(word),
+            })
+            .cloned()
+            .collect();
+        if pool.is_empty() { words.to_vec() } else { pool }
+    }
+}
+
+/// The secret for daily puzzle `puzzle`. Every word comes up once before any repeats,
+/// in an order fixed by `seed`, so everyone with the same list and seed gets the same
+/// word on the same day.
+pub fn daily_secret(words: &[String], puzzle: i64, seed: u64) -> Option<&String> {
+    // FNV-1a over the seed and word, which unlike a seeded shuffle can't change
+    // between library versions
+    let rank = |word: &String| {
+        seed.to_le_bytes().iter().chain(word.as_bytes()).fold(0xcbf29ce484222325u64, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
+    };
+    let mut order: Vec<&String> = words.iter().collect();
+    order.sort_by_key(|word| (rank(word), *word));
+    order.dedup();
+    if order.is_empty() {
+        return None;
+    }
+    Some(order[puzzle.rem_euclid(order.len() as i64) as usize])
+}
+
+/// A daily game as written to disk: which puzzle it was and the guesses played.
+/// The secret isn't stored; it is worked out again from the date, epoch and rotation.
+///
+/// ```text
+/// wordle-solver daily game
+/// date 2026-10-16
+/// epoch 2021-06-19
+/// rotation 0
+/// hard-mode false
+/// difficulty normal
+/// hints 1
+/// hint-budget 3
+/// ranked false
+/// guess irate
+/// guess shore
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SavedGame {
+    pub date: String,
+    pub epoch: String,
+    pub rotation: u64,
+    pub hard_mode: bool,
+    pub difficulty: Difficulty,
+    pub hints_used: usize,
+    /// Hints allowed per game, `None` for any number
+    pub hint_budget: Option<usize>,
+    /// Whether the game counts toward the ranked ledger
+    pub ranked: bool,
+    pub guesses: Vec<String>,
+}
+
+impl SavedGame {
+    pub fn write(&self, path: &str) -> Result<()> {
+        let mut text = format!("{}\n", SAVED_HEADER);
+        text.push_str(&format!("date {}\nepoch {}\nrotation {}\n", self.date, self.epoch, self.rotation));
+        text.push_str(&format!("hard-mode {}\ndifficulty {}\n", self.hard_mode, self.difficulty.label()));
+        text.push_str(&format!("hints {}\n", self.hints_used));
+        if let Some(budget) = self.hint_budget {
+            text.push_str(&format!("hint-budget {}\n", budget));
+        }
+        text.push_str(&format!("ranked {}\n", self.ranked));
+        for guess in &self.guesses {
+            text.push_str(&format!("guess {}\n", guess));
+        }
+        fs::write(path, text).map_err(|source| Error::Io { path: path.to_string(), source })
+    }
+
+    pub fn read(path: &str) -> Result<Self> {
+        let text = fs::read_to_string(path).map_err(|source| Error::Io { path: path.to_string(), source })?;
+        let invalid = |reason: String| Error::InvalidSession { path: path.to_string(), reason };
+
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+        if lines.next() != Some(SAVED_HEADER) {
+            return Err(invalid(format!("missing '{}' header", SAVED_HEADER)));
+        }
+        let (mut date, mut epoch, mut rotation, mut hard_mode) = (None, None, None, None);
+        // Games saved before difficulty levels existed were all normal
+        let mut difficulty = Difficulty::Normal;
+        let (mut hints_used, mut hint_budget, mut ranked) = (0, None, false);
+        let mut guesses = Vec::new();
+        for line in lines {
+            let (key, value) = line.split_once(' ').ok_or_else(|| invalid(format!("no value in '{}'", line)))?;
+            match key {
+                "date" => date = Some(value.to_string()),
+                "epoch" => epoch = Some(value.to_string()),
+                "rotation" => {
+                    rotation = Some(value.parse().map_err(|_| invalid(format!("bad rotation '{}'", value)))?);
+                }
+                "hard-mode" => {
+                    hard_mode = Some(value.parse().map_err(|_| invalid(format!("bad hard mode setting '{}'", value)))?);
+                }
+                "difficulty" => {
+                    difficulty = Difficulty::from_label(value).ok_or_else(|| invalid(format!("bad difficulty '{}'", value)))?;
+                }
+                "hints" => hints_used = value.parse().map_err(|_| invalid(format!("bad hint count '{}'", value)))?,
+                "hint-budget" => {
+                    hint_budget = Some(value.parse().map_err(|_| invalid(format!("bad hint budget '{}'", value)))?);
+                }
+                "ranked" => ranked = value.parse().map_err(|_| invalid(format!("bad ranked setting '{}'", value)))?,
+                "guess" => guesses.push(value.to_string()),
+                _ => return Err(invalid(format!("unknown key '{}'", key))),
+            }
+        }
+        match (date, epoch, rotation, hard_mode) {
+            (Some(date), Some(epoch), Some(rotation), Some(hard_mode)) => {
+                Ok(Self { date, epoch, rotation, hard_mode, difficulty, hints_used, hint_budget, ranked, guesses })
+            }
+            _ => Err(invalid("missing the date, epoch, rotation or hard mode setting".to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{self, temp_path};
+
+    fn words() -> Vec<String> {
+        test_util::words(&["break", "bread", "cream", "dream", "crane", "irate", "great", "treat", "slimy", "spoon"])
+    }
+
+    #[test]
+    fn daily_secrets_cycle_through_every_word() {
+        let words = words();
+        let cycle: Vec<&String> = (0..words.len() as i64).map(|puzzle| daily_secret(&words, puzzle, 0).unwrap()).collect();
+        let mut seen = cycle.clone();
+        seen.sort();
+        seen.dedup();
+        assert_eq!(seen.len(), words.len());
+        // The cycle repeats, wraps before the first puzzle, and ignores the list's order
+        assert_eq!(daily_secret(&words, words.len() as i64, 0), Some(cycle[0]));
+        assert_eq!(daily_secret(&words, -1, 0), cycle.last().copied());
+        let reversed: Vec<String> = words.iter().rev().cloned().collect();
+        assert_eq!(daily_secret(&reversed, 3, 0), Some(cycle[3]));
+        // Another seed gives another order
+        let reseeded: Vec<&String> = (0..words.len() as i64).map(|puzzle| daily_secret(&words, puzzle, 1).unwrap()).collect();
+        assert_ne!(reseeded, cycle);
+        assert_eq!(daily_secret(&[], 0, 0), None);
+    }
+
+    #[test]
+    fn saved_games_round_trip() {
+        let path = temp_path("daily.txt");
+        let saved = SavedGame {
+            date: "2026-10-16".to_string(),
+            epoch: "2021-06-19".to_string(),
+            rotation: 3,
+            hard_mode: true,
+            difficulty: Difficulty::Hard,
+            hints_used: 1,
+            hint_budget: Some(2),
+            ranked: false,
+            guesses: vec!["irate".to_string(), "shore".to_string()],
+        };
+        saved.write(&path).unwrap();
+        let read = SavedGame::read(&path);
+
+        // Games saved before difficulty, hints and ranked play existed still load
+        fs::write(&path, "wordle-solver daily game\ndate 2026-10-16\nepoch 2021-06-19\nrotation 0\nhard-mode false\nguess irate\n").unwrap();
+        let old = SavedGame::read(&path);
+        fs::write(&path, "wordle-solver daily game\ndate 2026-10-16\nrotation x\n").unwrap();
+        let broken = SavedGame::read(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(read.unwrap(), saved);
+        let old = old.unwrap();
+        assert_eq!((old.difficulty, old.hints_used, old.hint_budget, old.ranked), (Difficulty::Normal, 0, None, false));
+        assert_eq!(old.guesses, ["irate"]);
+        assert!(matches!(broken, Err(Error::InvalidSession { .. })));
+    }
+
+    #[test]
+    fn hard_mode_keeps_revealed_letters() {
+        let mut game = Game::new("break", words(), 6);
+        game.set_hard_mode(true);
+        assert_eq!(game.guess("crane").unwrap().as_str(), "BGYBY");
+        // Drops the R, A and E that crane revealed
+        assert!(matches!(game.guess("slimy"), Err(Error::HardMode { .. })));
+        assert_eq!(game.turns().len(), 1);
+        assert_eq!(game.guess("bread").unwrap().as_str(), "GGGGB");
+
+        // The same guess is fine outside hard mode
+        let mut game = Game::new("break", words(), 6);
+        game.guess("crane").unwrap();
+        assert!(game.guess("slimy").is_ok());
+    }
+
+    #[test]
+    fn share_grid_shows_only_colors() {
+        let mut game = Game::new("break", words(), 6);
+        game.guess("crane").unwrap();
+        game.guess("break").unwrap();
+        assert_eq!(game.share_grid(1945, Palette::Standard), "Wordle-solver #1945 2/6\n\n⬛🟩🟨⬛🟨\n🟩🟩🟩🟩🟩");
+
+        let mut game = Game::new("break", words(), 2);
+        game.set_hard_mode(true);
+        game.record_hint();
+        assert_eq!(game.share_grid(7, Palette::Standard), "Wordle-solver #7 ?/2* (1 hint)\n");
+        game.guess("slimy").unwrap();
+        game.guess("spoon").unwrap();
+        assert_eq!(game.share_grid(7, Palette::Standard), "Wordle-solver #7 X/2* (1 hint)\n\n⬛⬛⬛⬛⬛\n⬛⬛⬛⬛⬛");
+    }
+
+    #[test]
+    fn color_blind_palette_swaps_green_and_yellow() {
+        let mut game = Game::new("break", words(), 6);
+        game.guess("crane").unwrap();
+        assert!(game.share_grid(1, Palette::ColorBlind).ends_with("\n⬛🟧🟦⬛🟦"));
+    }
+
+    #[test]
+    fn difficulty_picks_the_secret_pool() {
+        let words = words();
+        let weights = WordWeights::default();
+        let pool = |difficulty: Difficulty| difficulty.secret_pool(&words, &weights);
+        assert_eq!(pool(Difficulty::Normal), words);
+        assert_eq!(pool(Difficulty::Easy), ["break", "bread", "cream", "dream", "crane", "great", "treat", "spoon"]);
+        // Uncommon words, plus common ones that repeat a letter
+        assert_eq!(pool(Difficulty::Hard), ["irate", "treat", "slimy", "spoon"]);
+
+        // A list weight above 1 makes a word common too
+        let weights: WordWeights = [("slimy".to_string(), 2.0)].into_iter().collect();
+        assert!(Difficulty::Easy.secret_pool(&words, &weights).contains(&"slimy".to_string()));
+
+        // A filter that leaves nothing falls back to every word
+        let rare = vec!["xylyl".to_string()];
+        assert_eq!(Difficulty::Easy.secret_pool(&rare, &WordWeights::default()), rare);
+    }
+
+    #[test]
+    fn multi_game_locks_solved_boards() {
+        let secrets = ["break".to_string(), "spoon".to_string()];
+        let mut game = MultiGame::new(&secrets, &words(), 7);
+        game.guess("break").unwrap();
+        assert_eq!(game.boards()[0].status(), GameStatus::Won);
+        assert_eq!(game.status(), GameStatus::Ongoing);
+
+        // A bad word changes no board; later guesses skip the solved one
+        assert!(matches!(game.guess("zebra"), Err(Error::UnknownWord(_))));
+        game.guess("spoon").unwrap();
+        assert_eq!(game.boards()[0].turns().len(), 1);
+        assert_eq!(game.boards()[1].turns().len(), 2);
+        assert_eq!((game.status(), game.guesses()), (GameStatus::Won, 2));
+        assert!(matches!(game.guess("crane"), Err(Error::GameOver)));
+    }
+
+    #[test]
+    fn multi_game_is_lost_when_the_guesses_run_out() {
+        let secrets = ["break".to_string(), "spoon".to_string()];
+        let mut game = MultiGame::new(&secrets, &words(), 2);
+        game.guess("break").unwrap();
+        game.guess("crane").unwrap();
+        assert_eq!(game.status(), GameStatus::Lost);
+    }
+
+    #[test]
+    fn giving_up_loses_an_open_game_only() {
+        let mut game = Game::new("break", words(), 6);
+        game.guess("crane").unwrap();
+        game.give_up();
+        assert!(game.given_up());
+        assert_eq!(game.status(), GameStatus::Lost);
+        assert!(matches!(game.guess("break"), Err(Error::GameOver)));
+
+        let mut game = Game::new("break", words(), 6);
+        game.guess("break").unwrap();
+        game.give_up();
+        assert!(!game.given_up());
+        assert_eq!(game.status(), GameStatus::Won);
+    }
+}
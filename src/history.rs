@@ -0,0 +1,159 @@
+//! Games given as a history of turns, `crane=BYBBG,slimy=BBGYB`, played into a solver
+//! and graded turn by turn against the best guess the candidates allowed.
+
+use crate::engine::SolverEngine;
+use crate::error::{Error, Result, TurnConflict};
+use crate::oracle::GuessScore;
+use crate::solver::{GameStatus, TurnRecord, WordleSolver};
+
+/// Splits `crane=BYBBG,slimy=BBGYB` (or with semicolons) into guess and results pairs,
+/// or `None` if a turn has no `=`.
+pub fn parse_history(history: &str) -> Option<Vec<(&str, &str)>> {
+    history
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|turn| !turn.is_empty())
+        .map(|turn| turn.split_once('=').map(|(guess, results)| (guess.trim(), results.trim())))
+        .collect()
+}
+
+/// Plays `turns` into `solver` in order and returns where the game stands after the
+/// last. The first turn refused, left with no word that fits, or coming after the game
+/// is over stops the rest with an [`Error::HistoryTurn`]; the turns before it stay played.
+pub fn apply_turns(solver: &mut WordleSolver, turns: &[(&str, &str)]) -> Result<GameStatus> {
+    play_turns(solver, turns, |_| (), |_, ()| {})
+}
+
+/// One turn of an analyzed game beside the best guess the candidates allowed before it.
+#[derive(Clone, Debug)]
+pub struct TurnAnalysis {
+    pub turn: TurnRecord,
+    /// The top-scored guess before the turn, if anything was left to score
+    pub best: Option<GuessScore>,
+    /// The turn's expected information as a share of the best guess's, from 0 to 1
+    pub share: f64,
+}
+
+impl TurnAnalysis {
+    pub fn grade(&self) -> char {
+        information_grade(self.share)
+    }
+}
+
+/// A history graded turn by turn.
+#[derive(Clone, Debug)]
+pub struct HistoryAnalysis {
+    pub turns: Vec<TurnAnalysis>,
+    /// Where the game stood after the last turn
+    pub status: GameStatus,
+}
+
+impl HistoryAnalysis {
+    /// The mean of the turns' shares of the best expected information.
+    pub fn information_share(&self) -> f64 {
+        self.turns.iter().map(|turn| turn.share).sum::<f64>() / self.turns.len().max(1) as f64
+    }
+
+    pub fn grade(&self) -> char {
+        information_grade(self.information_share())
+    }
+}
+
+/// Letter grade for a guess worth `share` of the best guess's expected information.
+pub fn information_grade(share: f64) -> char {
+    match share {
+        share if share >= 0.99 => 'A',
+        share if share >= 0.9 => 'B',
+        share if share >= 0.75 => 'C',
+        share if share >= 0.5 => 'D',
+        _ => 'F',
+    }
+}
+
+/// Plays `turns` into `solver` like [`apply_turns`], comparing each guess with the
+/// best one the candidates allowed at the time.
+pub fn analyze_turns(solver: &mut WordleSolver, turns: &[(&str, &str)]) -> Result<HistoryAnalysis> {
+    let mut analyzed = Vec::new();
+    let best_guess = |solver: &WordleSolver| solver.scored_guesses().into_iter().next();
+    let status = play_turns(solver, turns, best_guess, |solver, best| {
+        let turn = solver.turns().last().unwrap();
+        // A turn that wins outright can't be improved on, and a guess from outside the
+        // guess pool can beat the pool's best
+        let share = match &best {
+            _ if solver.status() == GameStatus::Won => 1.0,
+            Some(best) if best.entropy > 0.0 => (turn.expected_information / best.entropy).min(1.0),
+            _ => 1.0,
+        };
+        analyzed.push(TurnAnalysis { turn: turn.clone(), best, share });
+    })?;
+    Ok(HistoryAnalysis { turns: analyzed, status })
+}
+
+// Plays `turns` in order, calling `before` ahead of each turn and handing what it
+// returns to `after` once the turn is played
+fn play_turns<T>(
+    solver: &mut WordleSolver,
+    turns: &[(&str, &str)],
+    mut before: impl FnMut(&WordleSolver) -> T,
+    mut after: impl FnMut(&WordleSolver, T),
+) -> Result<GameStatus> {
+    let mut status = solver.status();
+    for (idx, &(guess, results)) in turns.iter().enumerate() {
+        let stop = |conflict| Error::HistoryTurn { turn: idx + 1, guess: guess.to_string(), results: results.to_string(), conflict };
+        if status != GameStatus::Ongoing {
+            return Err(stop(TurnConflict::GameOver));
+        }
+        let seen = before(solver);
+        status = solver.apply(guess, &results.to_uppercase()).map_err(|e| stop(TurnConflict::Refused(Box::new(e))))?;
+        after(solver, seen);
+        if status == GameStatus::Ongoing && solver.candidates().is_empty() {
+            return Err(stop(TurnConflict::NoWordFits));
+        }
+    }
+    Ok(status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oracle::simulate_results;
+    use crate::test_util::words;
+
+    fn solver() -> WordleSolver {
+        let mut solver =
+            WordleSolver::builder_from(words(&["break", "bread", "cream", "dream", "crane", "slimy"])).build().unwrap();
+        solver.start();
+        solver
+    }
+
+    #[test]
+    fn histories_read_either_separator() {
+        assert_eq!(parse_history(" crane=BYBBG; slimy = BBGYB ,"), Some(vec![("crane", "BYBBG"), ("slimy", "BBGYB")]));
+        assert_eq!(parse_history("crane BYBBG"), None);
+    }
+
+    #[test]
+    fn turns_stop_at_the_first_that_cannot_be_played() {
+        let play = |turns: &[(&str, &str)]| apply_turns(&mut solver(), turns);
+        assert_eq!(play(&[("slimy", "bbbbb"), ("break", "GGGGG")]).unwrap(), GameStatus::Won);
+        assert!(matches!(
+            play(&[("break", "GGGGG"), ("slimy", "BBBBB")]),
+            Err(Error::HistoryTurn { turn: 2, conflict: TurnConflict::GameOver, .. })
+        ));
+        assert!(matches!(play(&[("crane", "YYYYY")]), Err(Error::HistoryTurn { turn: 1, conflict: TurnConflict::NoWordFits, .. })));
+        assert!(matches!(play(&[("crane", "BYB")]), Err(Error::HistoryTurn { turn: 1, conflict: TurnConflict::Refused(_), .. })));
+    }
+
+    #[test]
+    fn analysis_grades_each_turn_against_the_best_guess() {
+        let mut solver = solver();
+        let results = simulate_results("slimy", "break");
+        let analysis = analyze_turns(&mut solver, &[("slimy", &results), ("break", "GGGGG")]).unwrap();
+        assert_eq!(analysis.status, GameStatus::Won);
+        assert_eq!(analysis.turns.len(), 2);
+        // Slimy only tells itself apart from the rest, well short of the best opener
+        assert!(analysis.turns[0].share < 0.75, "{}", analysis.turns[0].share);
+        assert_eq!(analysis.turns[1].grade(), 'A');
+        assert_eq!(information_grade(0.8), 'C');
+    }
+}
@@ -0,0 +1,61 @@
+//! A Wordle solver that picks each guess to narrow down the candidates as fast as possible.
+//!
+//! [`WordleSolver`] holds a game in progress. Load a word list with
+//! [`WordleSolver::new`], adjust its public settings, call
+//! [`WordleSolver::start`] for the opening guess, then feed the feedback for
+//! each guess to [`WordleSolver::next_turn`]:
+//!
+//! ```no_run
+//! use solver_project::{GameStatus, Strategy, WordleSolver};
+//!
+//! let mut solver = WordleSolver::new("words.txt").unwrap();
+//! solver.strategy = Strategy::Entropy;
+//! println!("{:?}", solver.start());
+//! if solver.next_turn("BYBBG").unwrap() == GameStatus::Ongoing {
+//!     println!("{:?}", solver.current_guess());
+//! }
+//! ```
+//!
+//! The feedback oracle and information measures live in [`oracle`], the guess
+//! selection strategies in [`strategy`], and word list parsing in [`load`].
+//! Every fallible call reports an [`Error`].
+
+pub mod cancel;
+pub mod clock;
+pub mod constraints;
+pub mod daily;
+pub mod engine;
+pub mod error;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod game;
+pub mod history;
+pub mod load;
+pub mod logging;
+pub mod multi;
+pub mod observer;
+pub mod oracle;
+mod par;
+mod session;
+pub mod solver;
+pub mod stats;
+pub mod strategy;
+#[cfg(test)]
+mod test_util;
+
+pub use cancel::CancelToken;
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use constraints::Constraints;
+pub use daily::DailySettings;
+pub use engine::SolverEngine;
+pub use game::Game;
+pub use history::{HistoryAnalysis, TurnAnalysis};
+pub use error::{Error, Result, TurnConflict};
+pub use load::{EmbeddedSource, FileSource, Word, WordSource};
+pub use logging::FileLogger;
+pub use multi::MultiSolver;
+pub use observer::SolverObserver;
+pub use oracle::{Feedback, PatternCode};
+pub use solver::{solve_batch, BatchSummary, CandidateFamily, Elimination, GameRecord, GameStatus, ReplayStep, TurnRecord, WordleSolver, WordleSolverBuilder};
+pub use stats::PlayerStats;
+pub use strategy::{BeamSettings, PhaseThresholds, ProbePolicy, Strategy, TiePolicy};
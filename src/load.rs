@@ -0,0 +1,307 @@
+//! Reading word lists.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::str::FromStr;
+use crate::error::{Error, Result};
+
+/// Everyday English words, the kind the official puzzle picks its answers from, each
+/// weighted by its Zipf frequency: log10 of how many times it is used per billion
+/// words of English, so "about" weighs 6.46 and "tapir" 2.05.
+pub const COMMON_WORDS: &str = include_str!("common_words.txt");
+
+/// The common words as a ready-made five-letter word list, for running without one.
+#[cfg(feature = "bundled-words")]
+pub const BUNDLED_WORDS: EmbeddedSource = EmbeddedSource { name: "the bundled word list", bytes: COMMON_WORDS.as_bytes() };
+
+/// Word length of the classic puzzle.
+pub const DEFAULT_WORD_LENGTH: usize = 5;
+
+/// Each of the [`COMMON_WORDS`] with its frequency weight.
+pub fn common_word_weights() -> HashMap<String, f64> {
+    parse_word_list(COMMON_WORDS.as_bytes(), DEFAULT_WORD_LENGTH).into_iter().collect()
+}
+
+/// Somewhere a word list can be read from, so words don't have to come from the filesystem.
+pub trait WordSource: fmt::Debug + Send + Sync {
+    /// How the source is named in errors, such as a file path.
+    fn name(&self) -> String;
+
+    /// Every word of `length` letters with its weight, skipping anything unusable.
+    fn words(&self, length: usize) -> Result<Vec<(String, f64)>>;
+}
+
+impl<S: WordSource + ?Sized> WordSource for Box<S> {
+    fn name(&self) -> String {
+        (**self).name()
+    }
+
+    fn words(&self, length: usize) -> Result<Vec<(String, f64)>> {
+        (**self).words(length)
+    }
+}
+
+/// A word list file, in the format read by [`load_word_list`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileSource(pub String);
+
+impl WordSource for FileSource {
+    fn name(&self) -> String {
+        self.0.clone()
+    }
+
+    fn words(&self, length: usize) -> Result<Vec<(String, f64)>> {
+        load_word_list(&self.0, length)
+    }
+}
+
+/// A word list compiled into the program with `include_bytes!`, in the same format as a file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EmbeddedSource {
+    pub name: &'static str,
+    pub bytes: &'static [u8],
+}
+
+impl WordSource for EmbeddedSource {
+    fn name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn words(&self, length: usize) -> Result<Vec<(String, f64)>> {
+        Ok(parse_word_list(self.bytes, length))
+    }
+}
+
+/// Words already in memory, each with a weight of 1.
+impl WordSource for Vec<String> {
+    fn name(&self) -> String {
+        "in-memory word list".to_string()
+    }
+
+    fn words(&self, length: usize) -> Result<Vec<(String, f64)>> {
+        Ok(self
+            .iter()
+            .map(|word| word.trim().to_lowercase())
+            .filter(|word| valid_word(word, length))
+            .map(|word| (word, 1.0))
+            .collect())
+    }
+}
+
+/// Reads one word per line, each optionally followed by a tab and a positive weight.
+/// Lines that aren't a word of `length` letters, or carry an invalid weight, are skipped.
+pub fn load_word_list(path: &str, length: usize) -> Result<Vec<(String, f64)>> {
+    let file = File::open(path).map_err(|source| Error::Io { path: path.to_string(), source })?;
+    Ok(parse_word_list(BufReader::new(file), length))
+}
+
+pub(crate) fn parse_word_list(reader: impl BufRead, length: usize) -> Vec<(String, f64)> {
+    reader.lines().filter_map(|line| parse_line(&line.ok()?, length).ok()).collect()
+}
+
+// One line of a word list as a word and its weight
+fn parse_line(line: &str, length: usize) -> std::result::Result<(String, f64), LineProblem> {
+    let mut fields = line.trim().split('\t');
+    let word = fields.next().unwrap_or_default().trim().to_lowercase();
+    let weight = match fields.next() {
+        Some(weight) => weight.trim().parse::<f64>().ok().filter(|w| w.is_finite() && *w > 0.0).ok_or(LineProblem::BadWeight)?,
+        None => 1.0,
+    };
+    if valid_word(&word, length) {
+        Ok((word, weight))
+    } else {
+        Err(LineProblem::NotAWord)
+    }
+}
+
+/// Why a line of a word list doesn't add a word.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineProblem {
+    /// Not a word of the expected length made only of letters
+    NotAWord,
+    /// A weight that isn't a positive number
+    BadWeight,
+    /// A word already listed on the given line
+    Duplicate { first_line: usize },
+}
+
+impl fmt::Display for LineProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineProblem::NotAWord => write!(f, "not a word of the expected length"),
+            LineProblem::BadWeight => write!(f, "weight is not a positive number"),
+            LineProblem::Duplicate { first_line } => write!(f, "repeats the word on line {}", first_line),
+        }
+    }
+}
+
+/// A line of a word list that loading skips or that repeats an earlier word.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineIssue {
+    /// Line number, counting from 1
+    pub line: usize,
+    pub text: String,
+    pub problem: LineProblem,
+}
+
+/// A word list checked line by line: the usable words, first copy of each kept, and
+/// every line that was left out. Blank lines are ignored.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WordListAudit {
+    pub words: Vec<(String, f64)>,
+    pub issues: Vec<LineIssue>,
+}
+
+/// Reads the word list at `path` like [`load_word_list`], but reports what it skips.
+pub fn audit_word_list(path: &str, length: usize) -> Result<WordListAudit> {
+    let text = std::fs::read_to_string(path).map_err(|source| Error::Io { path: path.to_string(), source })?;
+    let mut audit = WordListAudit::default();
+    let mut first_lines: HashMap<String, usize> = HashMap::new();
+    for (idx, text) in text.lines().enumerate() {
+        let line = idx + 1;
+        if text.trim().is_empty() {
+            continue;
+        }
+        let problem = match parse_line(text, length) {
+            Ok((word, _)) if first_lines.contains_key(&word) => LineProblem::Duplicate { first_line: first_lines[&word] },
+            Ok((word, weight)) => {
+                first_lines.insert(word.clone(), line);
+                audit.words.push((word, weight));
+                continue;
+            }
+            Err(problem) => problem,
+        };
+        audit.issues.push(LineIssue { line, text: text.to_string(), problem });
+    }
+    Ok(audit)
+}
+
+/// Writes `words` in the format [`load_word_list`] reads, leaving out weights of 1.
+pub fn format_word_list(words: &[(String, f64)]) -> String {
+    words
+        .iter()
+        .map(|(word, weight)| if *weight == 1.0 { format!("{}\n", word) } else { format!("{}\t{}\n", word, weight) })
+        .collect()
+}
+
+/// Whether `word` is `length` lowercase ASCII letters.
+pub fn valid_word(word: &str, length: usize) -> bool {
+    word.len() == length && word.chars().all(|c| c.is_ascii_lowercase())
+}
+
+/// A word of lowercase ASCII letters, checked when it is built. Parsing trims the
+/// input and ignores case.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Word(String);
+
+impl Word {
+    /// Parses a word of `length` letters.
+    pub fn parse(word: &str, length: usize) -> Result<Self> {
+        match Self::try_from(word) {
+            Ok(parsed) if parsed.0.len() == length => Ok(parsed),
+            _ => Err(Error::InvalidWord { word: word.to_string(), length }),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl TryFrom<&str> for Word {
+    type Error = Error;
+
+    /// Accepts a word of any length, as long as it is only letters.
+    fn try_from(word: &str) -> Result<Self> {
+        let normalized = word.trim().to_lowercase();
+        if !normalized.is_empty() && valid_word(&normalized, normalized.len()) {
+            Ok(Self(normalized))
+        } else {
+            Err(Error::InvalidWord { word: word.to_string(), length: word.trim().chars().count() })
+        }
+    }
+}
+
+impl FromStr for Word {
+    type Err = Error;
+
+    fn from_str(word: &str) -> Result<Self> {
+        Self::try_from(word)
+    }
+}
+
+impl AsRef<str> for Word {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Word {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::temp_path;
+
+    #[test]
+    fn weighted_lines_keep_their_weights() {
+        let path = temp_path("weighted");
+        std::fs::write(&path, "crane\t2.5\nSLATE\nirate\t0.5\n").unwrap();
+        assert_eq!(
+            load_word_list(&path, 5).unwrap(),
+            [("crane".to_string(), 2.5), ("slate".to_string(), 1.0), ("irate".to_string(), 0.5)]
+        );
+        // Bad weights and words of other lengths are skipped
+        std::fs::write(&path, "crane\t0\nslate\t-1\nirate\tmany\nstare\tinf\ncranes\t2\nsnare\t3\n").unwrap();
+        assert_eq!(load_word_list(&path, 5).unwrap(), [("snare".to_string(), 3.0)]);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn words_parse_trimmed_and_lowercased() {
+        assert_eq!(Word::parse(" Crane\n", 5).unwrap().as_str(), "crane");
+        assert_eq!("SLATE".parse::<Word>().unwrap().to_string(), "slate");
+        assert_eq!(Word::try_from("ab").unwrap().into_string(), "ab");
+        for word in ["cranes", "cr4ne", "crâne", "", "   "] {
+            assert!(matches!(Word::parse(word, 5), Err(Error::InvalidWord { length: 5, .. })), "{:?}", word);
+        }
+        assert!(Word::try_from("two words").is_err());
+    }
+
+    #[cfg(feature = "bundled-words")]
+    #[test]
+    fn bundled_words_are_a_usable_word_list() {
+        let words = BUNDLED_WORDS.words(DEFAULT_WORD_LENGTH).unwrap();
+        assert_eq!(words.len(), COMMON_WORDS.lines().count());
+        assert!(words.iter().all(|(word, weight)| valid_word(word, DEFAULT_WORD_LENGTH) && *weight > 0.0));
+    }
+
+    #[test]
+    fn audit_reports_every_skipped_line() {
+        let path = temp_path("audit.txt");
+        std::fs::write(&path, "crane\n\nslate\t2\ncr4ne\nirate\tnone\nCRANE\n").unwrap();
+        let audit = audit_word_list(&path, 5).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(audit.words, [("crane".to_string(), 1.0), ("slate".to_string(), 2.0)]);
+        let problems: Vec<(usize, LineProblem)> = audit.issues.iter().map(|issue| (issue.line, issue.problem)).collect();
+        assert_eq!(
+            problems,
+            [(4, LineProblem::NotAWord), (5, LineProblem::BadWeight), (6, LineProblem::Duplicate { first_line: 1 })]
+        );
+        // The deduplicated words read back the same
+        let formatted = format_word_list(&audit.words);
+        assert_eq!(formatted, "crane\nslate\t2\n");
+        assert_eq!(parse_word_list(formatted.as_bytes(), 5), audit.words);
+    }
+}
@@ -0,0 +1,138 @@
+//! A log file recording what happened in a game, for looking into it after the fact.
+
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{LineWriter, Write};
+use std::sync::{Arc, Mutex};
+use std::time::UNIX_EPOCH;
+use crate::clock::{Clock, SystemClock};
+use crate::error::{Error, Result};
+use crate::observer::SolverObserver;
+use crate::solver::{GameStatus, TurnRecord};
+
+/// How much a log line matters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Level {
+    /// Every suggestion the solver computes
+    Debug,
+    /// Turns played, undos and game results
+    Info,
+    /// Failures only
+    Error,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Error => "ERROR",
+        })
+    }
+}
+
+/// Appends one timestamped line per event to a file. Add it to a solver with
+/// [`WordleSolver::add_observer`](crate::WordleSolver::add_observer) to record its
+/// suggestions and turns, and call [`FileLogger::log`] for anything else, such as errors.
+#[derive(Debug)]
+pub struct FileLogger {
+    file: Mutex<LineWriter<File>>,
+    clock: Arc<dyn Clock>,
+    min_level: Level,
+}
+
+impl FileLogger {
+    /// Opens `path` for appending, creating it if needed.
+    pub fn open(path: &str) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|source| Error::Io { path: path.to_string(), source })?;
+        Ok(Self { file: Mutex::new(LineWriter::new(file)), clock: Arc::new(SystemClock), min_level: Level::Debug })
+    }
+
+    /// Drops messages below `level` instead of writing them.
+    pub fn with_min_level(mut self, level: Level) -> Self {
+        self.min_level = level;
+        self
+    }
+
+    /// Takes timestamps from `clock` instead of the system time.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Writes `message` at `level`, stamped with the Unix time in milliseconds, unless
+    /// `level` is below the logger's minimum. Failures to write are ignored so logging
+    /// can never stop a game.
+    pub fn log(&self, level: Level, message: &str) {
+        if level < self.min_level {
+            return;
+        }
+        let millis = self.clock.now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+        if let Ok(mut file) = self.file.lock() {
+            let _ = writeln!(file, "{} {:<5} {}", millis, level, message);
+        }
+    }
+}
+
+impl SolverObserver for FileLogger {
+    fn suggestion_computed(&self, guess: &str) {
+        self.log(Level::Debug, &format!("suggested {}", guess));
+    }
+
+    fn turn_applied(&self, turn: &TurnRecord) {
+        self.log(
+            Level::Info,
+            &format!("played {} got {}, {} candidates left", turn.guess, turn.results, turn.candidates_after),
+        );
+    }
+
+    fn turns_undone(&self, turns_kept: usize) {
+        self.log(Level::Info, &format!("undone back to {} turns", turns_kept));
+    }
+
+    fn game_over(&self, status: GameStatus, attempts: usize) {
+        self.log(Level::Info, &format!("game over: {:?} after {} guesses", status, attempts));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::time::Duration;
+    use crate::clock::FixedClock;
+    use crate::test_util::temp_path;
+
+    #[test]
+    fn lines_are_stamped_by_the_clock() {
+        let path = temp_path("logger.log");
+        let clock = FixedClock(UNIX_EPOCH + Duration::from_millis(1_760_630_400_123));
+        let logger = FileLogger::open(&path).unwrap().with_clock(clock);
+        logger.log(Level::Info, "loaded 508 candidates");
+        logger.log(Level::Error, "bad results");
+        drop(logger);
+
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(text, "1760630400123 INFO  loaded 508 candidates\n1760630400123 ERROR bad results\n");
+    }
+
+    #[test]
+    fn messages_below_the_minimum_level_are_dropped() {
+        let path = temp_path("levels.log");
+        let logger = FileLogger::open(&path).unwrap().with_clock(FixedClock(UNIX_EPOCH)).with_min_level(Level::Info);
+        logger.log(Level::Debug, "suggested crane");
+        logger.log(Level::Info, "played crane got BYBBG, 8 candidates left");
+        logger.log(Level::Error, "bad results");
+        drop(logger);
+
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(text, "0 INFO  played crane got BYBBG, 8 candidates left\n0 ERROR bad results\n");
+    }
+}
@@ -1,228 +1,167 @@
-use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::{self, BufRead, BufReader};
-use rayon::prelude::*;
+use std::sync::Arc;
+use clap::Parser;
+use solver_project::clock::SystemClock;
+use solver_project::logging::Level;
+use solver_project::stats::game_log_path;
+use solver_project::{Error, FileLogger, PlayerStats};
+use cli::args::{Args, Command};
+use cli::{exit_code, log_error, messages, solve, stats_path, style, timed};
+use cli::games::{DailyArgs, play_daily, play_duel, play_endless, play_friend, play_multi, play_practice, solve_multi};
+use cli::reports::{analyze_history, bench_strategy, compare_strategies, print_player_stats, profile_solver, replay_transcript, review_games, search_words, simulate_games, suggest_from_history, verify_strategy};
+use cli::wordlist::run_wordlist_action;
+use messages::fill;
+
+mod cli;
 
-fn simulate_results(guess: &str, target: &str) -> String {
-    let mut results = vec!['B'; guess.len()];
-    let mut target_count: HashMap<char, i32> = HashMap::new();
-    for c in target.chars() {
-        *target_count.entry(c).or_insert(0) += 1;
-    }
-
-    for (i, (g, t)) in guess.chars().zip(target.chars()).enumerate() {
-        if g == t {
-            results[i] = 'G';
-            *target_count.get_mut(&g).unwrap() -= 1;
-        } else if target_count.get(&g).unwrap_or(&0) > &0 {
-            results[i] = 'Y';
-            *target_count.get_mut(&g).unwrap() -= 1;
-        }
-    }
-
-    let mut result_string = String::with_capacity(guess.len());
-    result_string.extend(results.iter());
-    result_string
-}
-
-fn calculate_entropy(word: &str, word_list: &[String]) -> f64 {
-    let total_words = word_list.len() as f64;
-
-    // Create pattern counts using an iterator and collect them into a hashmap
-    let pattern_counts = word_list
-        .iter()
-        .map(|target| simulate_results(word, target))
-        .fold(HashMap::new(), |mut acc, pattern| {
-            *acc.entry(pattern).or_insert(0) += 1;
-            acc
-        });
-
-    // Calculate entropy using an iterator
-    -pattern_counts.values().fold(0.0, |acc, &count| {
-        let probability = count as f64 / total_words;
-        acc + probability * probability.log2()
-    })
-}
-
-struct WordleSolver {
-    word_list: Vec<String>,
-    known_correct: HashMap<usize, char>,
-    known_wrong_positions: HashMap<char, HashSet<usize>>,
-    known_absent: HashSet<char>,
-    attempts: usize,
-    max_attempts: usize,
-    current_guess: Option<String>
-}
-
-impl WordleSolver {
-    fn new(word_list_path: &str) -> io::Result<Self> {
-        let file = File::open(word_list_path)?;
-        let reader = BufReader::new(file);
-        let word_list: Vec<String> = reader
-            .lines()
-            .filter_map(|line| {
-                let word = line.ok()?;
-                if word.len() == 5 {
-                    Some(word.to_lowercase())
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        Ok(Self {
-            word_list,
-            known_correct: HashMap::new(),
-            known_wrong_positions: HashMap::new(),
-            known_absent: HashSet::new(),
-            attempts: 0,
-            max_attempts: 6,
-            current_guess: None
-        })
+fn main() {
+    let args = Args::parse();
+    style::init(args.no_color, args.palette, args.no_animation);
+    messages::init(args.lang);
+    let messages = messages::catalog();
+    if let Some(Command::Wordlist { action }) = &args.command {
+        let clean = run_wordlist_action(action, args.length, args.json);
+        std::process::exit(if clean { exit_code::SUCCESS } else { exit_code::FAILURE });
     }
-
-    fn make_guess(&self) -> Option<String> {
-        self.word_list
-            .par_iter() // Use Rayon's parallel iterator
-            .map(|word| (word.clone(), calculate_entropy(word, &self.word_list)))
-            .max_by(|(_, entropy_a), (_, entropy_b)| entropy_a.partial_cmp(entropy_b).unwrap())
-            .map(|(word, _)| word)
-    }  
-
-    fn process_results(&mut self, guess: &str, results: &str) {
-        guess.chars().zip(results.chars()).enumerate().for_each(|(idx, (letter, status))| {
-            match status {
-                'G' => { self.known_correct.insert(idx, letter); }
-                'Y' => { self.known_wrong_positions.entry(letter).or_insert_with(HashSet::new).insert(idx); }
-                'B' => { 
-                    if !self.known_correct.values().any(|&v| v == letter) && !self.known_wrong_positions.contains_key(&letter) {
-                        self.known_absent.insert(letter);
-                    }
-                }
-                _ => {}
+    if let Some(Command::Stats { ranked }) = &args.command {
+        let Some(path) = stats_path(&args.stats_file, *ranked) else {
+            eprintln!("{}", messages.no_stats_dir);
+            std::process::exit(exit_code::USAGE);
+        };
+        match PlayerStats::load(&path) {
+            Ok(stats) => print_player_stats(&stats, args.json),
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(exit_code::FAILURE);
             }
-        });
-
-        self.word_list = self.word_list.iter().filter(|&word| self.is_possible_word(word)).cloned().collect();
-    }
-
-    fn is_possible_word(&self, word: &str) -> bool {
-        self.known_correct.iter().all(|(&idx, &letter)| word.chars().nth(idx).map_or(false, |w| w == letter))
-            && self.known_wrong_positions.iter().all(|(&letter, positions)| 
-                word.contains(letter) && positions.iter().all(|&idx| word.chars().nth(idx).unwrap_or('\0') != letter))
-            && self.known_absent.iter().all(|&letter| !word.contains(letter))
-    }
-
-    fn check_game_status(&self, results: &str) -> String {
-        if results.chars().all(|c| c == 'G') {
-            "won".to_string()
-        } else if self.attempts >= self.max_attempts {
-            "lost".to_string()
-        } else {
-            "ongoing".to_string()
         }
+        return;
     }
 
-    fn next_turn(&mut self, results: &str) -> String {
-        // Temporarily take the current_guess out of self to avoid mutable-immutable borrow conflict
-        let current_guess = self.current_guess.take();
-        if let Some(ref guess) = current_guess {
-            self.process_results(guess, results);
+    let builder = args.solver_builder();
+    let logger = args.log_file.as_deref().map(|path| match FileLogger::open(path) {
+        Ok(logger) => Arc::new(logger.with_min_level(args.log_level)),
+        Err(e) => {
+            eprintln!("{}", fill(messages.log_file_failed, &[&e]));
+            std::process::exit(exit_code::FAILURE);
         }
-        self.attempts += 1;
-        let game_status = self.check_game_status(results);
-
-        if game_status == "ongoing" {
-            self.current_guess = self.make_guess();
-        } else {
-            self.current_guess = None;
+    });
+    let mut solver = match timed(args.verbose, messages.timing_loading, || builder.build()) {
+        Ok(solver) => solver,
+        Err(e) => {
+            log_error(&logger, &format!("failed to set up the solver: {}", e));
+            eprintln!("{}", fill(messages.setup_failed, &[&e]));
+            std::process::exit(match e {
+                Error::Io { .. } | Error::NoWords { .. } | Error::Fetch { .. } => exit_code::DICTIONARY,
+                _ => exit_code::USAGE,
+            });
         }
+    };
+    if let Some(logger) = &logger {
+        logger.log(Level::Info, &format!("loaded {} candidates", solver.candidates().len()));
+        solver.add_observer(logger.clone());
+    }
 
-        // Put the current_guess back in case it was taken out
-        if self.current_guess.is_none() {
-            self.current_guess = current_guess;
+    // The first Ctrl-C stops whatever scoring is running; a second one quits
+    let cancel = solver.cancel_token();
+    let handler = ctrlc::set_handler(move || {
+        if cancel.is_cancelled() {
+            std::process::exit(130);
         }
-
-        game_status
+        cancel.cancel();
+        eprintln!("{}", messages.cancelling);
+    });
+    if let Err(e) = handler {
+        eprintln!("{}", fill(messages.ctrlc_failed, &[&e]));
     }
-}
 
-// Main function
-fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Usage: wordle_solver <wordlist_path>");
-        std::process::exit(1);
+    if let Some(Command::Bench) = &args.command {
+        bench_strategy(&mut solver, args.json);
+        return;
     }
-    let wordlist_path = &args[1];
-
-    let mut solver = match WordleSolver::new(wordlist_path) {
-        Ok(solver) => solver,
-        Err(e) => {
-            eprintln!("Failed to load word list: {}", e);
-            std::process::exit(1);
-        }
+    if let Some(Command::Compare { strategies }) = &args.command {
+        compare_strategies(&solver, strategies, args.json);
+        return;
+    }
+    let history = match &args.history_file {
+        Some(path) => match std::fs::read_to_string(path) {
+            Ok(text) => text.lines().collect::<Vec<_>>().join(","),
+            Err(e) => {
+                eprintln!("{}", fill(messages.history_file_failed, &[path, &e]));
+                std::process::exit(exit_code::USAGE);
+            }
+        },
+        None => args.history.clone().unwrap_or_default(),
     };
 
-    // Startup instructions
-    println!(
-        "·▄▄▄▄  ▄▄▄  ▄▄▄ .▄▄▌ ▐ ▄▌.▄▄ ·      \n\
-         ██▪ ██ ▀▄ █·▀▄.▀·██· █▌▐█▐█ ▀.      \n\
-         ▐█· ▐█▌▐▀▀▄ ▐▀▀▪▄██▪▐█▐▐▌▄▀▀▀█▄     \n\
-         ██. ██ ▐█•█▌▐█▄▄▌▐█▌██▐█▌▐█▄▪▐█     \n\
-         ▀▀▀▀▀• .▀  ▀ ▀▀▀  ▀▀▀▀ ▀▪ ▀▀▀▀      \n\
-         ▄▄▌ ▐ ▄▌      ▄▄▄  ·▄▄▄▄  ▄▄▌  ▄▄▄ .\n\
-         ██· █▌▐█▪     ▀▄ █·██▪ ██ ██•  ▀▄.▀·\n\
-         ██▪▐█▐▐▌ ▄█▀▄ ▐▀▀▄ ▐█· ▐█▌██▪  ▐▀▀▪▄\n\
-         ▐█▌██▐█▌▐█▌.▐▌▐█•█▌██. ██ ▐█▌▐▌▐█▄▄▌\n\
-          ▀▀▀▀ ▀▪ ▀█▄▀▪.▀  ▀▀▀▀▀▀• .▀▀▀  ▀▀▀ \n\
-         .▄▄ ·       ▄▄▌   ▌ ▐·▄▄▄ .▄▄▄      \n\
-         ▐█ ▀. ▪     ██•  ▪█·█▌▀▄.▀·▀▄ █·    \n\
-         ▄▀▀▀█▄ ▄█▀▄ ██▪  ▐█▐█•▐▀▀▪▄▐▀▀▄     \n\
-         ▐█▄▪▐█▐█▌.▐▌▐█▌▐▌ ███ ▐█▄▄▌▐█•█▌    \n\
-          ▀▀▀▀  ▀█▄▀▪.▀▀▀ . ▀   ▀▀▀ .▀  ▀    \n\
-         ========================\n\
-         Welcome to Drew's Wordle solver! Here's how to use it:\n\
-         * Type in the results of each guess as a string of 'G', 'Y', and 'B'.\n\
-         * 'G' for Green (correct position)\n\
-         * 'Y' for Yellow (wrong position)\n\
-         * 'B' for Black (not in the word)\n\
-         * Press enter to submit the results to the solver\n\
-         * Type 'EXIT' to quit the game\n\
-         ========================\n"
-    );
-
-    solver.current_guess = solver.make_guess();
-    println!("The solver's initial guess is: {}", solver.current_guess.as_ref().unwrap());
-
-    loop {
-        let mut results = String::new();
-        println!("Enter results for '{}': ", solver.current_guess.as_ref().unwrap());
-        io::stdin().read_line(&mut results).expect("Failed to read line");
-        let results = results.trim().to_uppercase();
-
-        if results == "EXIT" {
-            break;
-        }
-        if !valid_results(&results) {
-            println!("Invalid results. Please enter a 5-letter string of 'G', 'Y', and 'B'.");
-            continue;
-        }
-
-        let game_status = solver.next_turn(&results);
-        if game_status == "won" {
-            println!("Congratulations, you won!");
-            break;
-        } else if game_status == "lost" {
-            println!("Game over. Better luck next time!");
-            break;
-        } else {
-            println!("Next guess: {}", solver.current_guess.as_ref().unwrap());
-        }
+    if let Some(Command::Suggest { top }) = &args.command {
+        std::process::exit(suggest_from_history(&mut solver, &history, *top, args.json));
+    }
+    if let Some(Command::Simulate { games, seed, watch }) = &args.command {
+        simulate_games(&mut solver, *games, *seed, *watch, args.json);
+        return;
+    }
+    if let Some(Command::Daily { date, tz, epoch, rotation, hard, difficulty, timed, hint_budget, ranked, resume }) =
+        &args.command
+    {
+        let settings = DailyArgs {
+            date: date.as_deref(),
+            tz,
+            epoch,
+            rotation: *rotation,
+            hard_mode: *hard,
+            difficulty: *difficulty,
+            timed: *timed,
+            hint_budget: *hint_budget,
+            ranked: *ranked,
+        };
+        std::process::exit(play_daily(&solver, settings, resume.as_deref(), &args.stats_file, &SystemClock));
+    }
+    if let Some(Command::Duel { seed }) = &args.command {
+        std::process::exit(play_duel(&solver, *seed));
+    }
+    if let Some(Command::Friend) = &args.command {
+        std::process::exit(play_friend(&solver));
+    }
+    if let Some(Command::SolveMulti { boards }) = &args.command {
+        timed(args.verbose, messages.timing_opener, || solver.start());
+        std::process::exit(solve_multi(&solver, *boards as usize));
+    }
+    if let Some(Command::Multi { boards, seed }) = &args.command {
+        std::process::exit(play_multi(&solver, *boards as usize, *seed));
+    }
+    if let Some(Command::Endless { seed }) = &args.command {
+        std::process::exit(play_endless(&solver, *seed));
+    }
+    if let Some(Command::Practice { drill, seed }) = &args.command {
+        let stats_file = stats_path(&args.stats_file, false);
+        std::process::exit(play_practice(&solver, *drill, *seed, stats_file.as_deref()));
+    }
+    if let Some(Command::Review { game, ranked }) = &args.command {
+        let Some(stats_file) = stats_path(&args.stats_file, *ranked) else {
+            eprintln!("{}", messages.no_games_dir);
+            std::process::exit(exit_code::USAGE);
+        };
+        let log = game_log_path(stats_file.as_ref());
+        std::process::exit(review_games(&solver, &log.to_string_lossy(), *game, args.json));
+    }
+    if let Some(Command::Profile { runs }) = &args.command {
+        profile_solver(&solver, (*runs).max(1), args.json);
+        return;
+    }
+    if let Some(Command::Search { pattern, exclude, include }) = &args.command {
+        std::process::exit(search_words(&solver, pattern, include, exclude, args.json));
+    }
+    if let Some(Command::Analyze) = &args.command {
+        std::process::exit(analyze_history(&mut solver, &history, args.json));
+    }
+    if let Some(Command::Replay { transcript }) = &args.command {
+        let all_match = replay_transcript(&mut solver, transcript, args.json);
+        std::process::exit(if all_match { exit_code::SUCCESS } else { exit_code::FAILURE });
+    }
+    if args.verify {
+        let all_solved = verify_strategy(&mut solver, args.json);
+        std::process::exit(if all_solved { exit_code::SUCCESS } else { exit_code::FAILURE });
     }
-}
 
-// Additional helper functions
-fn valid_results(results: &str) -> bool {
-    results.len() == 5 && results.chars().all(|c| matches!(c, 'G' | 'Y' | 'B'))
-}
\ No newline at end of file
+    solve::run(solver, &args, &history, &logger);
+}
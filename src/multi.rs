@@ -0,0 +1,171 @@
+//! Several boards solved together, as in Dordle and Quordle: every guess is played on
+//! each board not yet solved, and each board gives its own feedback.
+
+use std::collections::HashSet;
+use crate::error::{Error, Result};
+use crate::load::Word;
+use crate::oracle::{parse_results_code, score_guess, GuessScore};
+use crate::par::*;
+use crate::solver::{GameStatus, WordleSolver};
+
+/// One solver per board, all fed the same guesses.
+#[derive(Clone)]
+pub struct MultiSolver {
+    boards: Vec<WordleSolver>,
+}
+
+impl MultiSolver {
+    /// Starts `boards` boards from `solver`, which should already be started. Each
+    /// extra board adds one guess to the budget, as in Dordle and Quordle.
+    pub fn new(solver: &WordleSolver, boards: usize) -> Self {
+        let mut board = solver.clone();
+        board.max_attempts = solver.max_attempts + boards.saturating_sub(1);
+        Self { boards: vec![board; boards] }
+    }
+
+    pub fn boards(&self) -> &[WordleSolver] {
+        &self.boards
+    }
+
+    /// Indexes of the boards still being played.
+    pub fn unsolved(&self) -> Vec<usize> {
+        (0..self.boards.len()).filter(|&idx| self.boards[idx].status() == GameStatus::Ongoing).collect()
+    }
+
+    /// Guesses played so far.
+    pub fn guesses(&self) -> usize {
+        self.boards.iter().map(WordleSolver::attempts).max().unwrap_or(0)
+    }
+
+    pub fn max_attempts(&self) -> usize {
+        self.boards.first().map_or(0, |board| board.max_attempts)
+    }
+
+    /// Won once every board is solved, lost once any board runs out of guesses.
+    pub fn status(&self) -> GameStatus {
+        let statuses: Vec<GameStatus> = self.boards.iter().map(WordleSolver::status).collect();
+        if statuses.contains(&GameStatus::Lost) {
+            GameStatus::Lost
+        } else if statuses.iter().all(|&status| status == GameStatus::Won) {
+            GameStatus::Won
+        } else {
+            GameStatus::Ongoing
+        }
+    }
+
+    /// Every allowed guess scored by the information it gives summed over the unsolved
+    /// boards, best first. The worst case is the largest bucket on any of them.
+    pub fn scored_guesses(&self) -> Vec<GuessScore> {
+        let unsolved: Vec<&WordleSolver> = self.unsolved().into_iter().map(|idx| &self.boards[idx]).collect();
+        let Some(first) = unsolved.first() else { return Vec::new() };
+        // Guesses that could solve a board, looked up once per guess rather than per comparison
+        let possible: HashSet<&str> = unsolved.iter().flat_map(|board| board.candidates()).map(String::as_str).collect();
+        let mut scores: Vec<(GuessScore, bool)> = first
+            .allowed_guesses()
+            .par_iter()
+            .map(|word| {
+                let per_board = unsolved.iter().map(|board| score_guess(word, board.candidates(), board.weights()));
+                let score = per_board.fold(GuessScore { word: word.clone(), entropy: 0.0, worst_case: 0 }, |total, score| GuessScore {
+                    word: total.word,
+                    entropy: total.entropy + score.entropy,
+                    worst_case: total.worst_case.max(score.worst_case),
+                });
+                (score, possible.contains(word.as_str()))
+            })
+            .collect();
+
+        // Highest combined entropy first, then guesses that could solve a board
+        scores.sort_by(|(a, a_possible), (b, b_possible)| {
+            b.entropy
+                .partial_cmp(&a.entropy)
+                .unwrap()
+                .then_with(|| b_possible.cmp(a_possible))
+                .then_with(|| a.worst_case.cmp(&b.worst_case))
+        });
+        scores.into_iter().map(|(score, _)| score).collect()
+    }
+
+    /// The guess to play next: a board's answer once only one word is left on it,
+    /// otherwise the best combined guess. Before any guess the boards are alike, so
+    /// the single-board opener serves.
+    pub fn make_guess(&self) -> Option<String> {
+        let unsolved = self.unsolved();
+        if self.guesses() == 0 {
+            return self.boards.first().and_then(|board| board.current_guess()).map(str::to_string);
+        }
+        if let Some(answer) = unsolved.iter().find_map(|&idx| match self.boards[idx].candidates() {
+            [answer] => Some(answer.clone()),
+            _ => None,
+        }) {
+            return Some(answer);
+        }
+        self.scored_guesses().into_iter().next().map(|score| score.word)
+    }
+
+    /// Plays `guess` with one result per unsolved board, in board order. Nothing is
+    /// applied unless every result is valid.
+    pub fn apply(&mut self, guess: &str, results: &[&str]) -> Result<GameStatus> {
+        let unsolved = self.unsolved();
+        if unsolved.is_empty() {
+            return Err(Error::GameOver);
+        }
+        if results.len() != unsolved.len() {
+            return Err(Error::ResultCount { results: results.len(), boards: unsolved.len() });
+        }
+        let length = self.boards[unsolved[0]].word_length();
+        let guess = Word::parse(guess, length)?;
+        let codes = results
+            .iter()
+            .map(|results| parse_results_code(&results.to_uppercase(), length))
+            .collect::<Result<Vec<_>>>()?;
+        // Each board only narrows its candidates; the next guess is picked for all of them
+        let mut boards = self.boards.clone();
+        for (&idx, code) in unsolved.iter().zip(codes) {
+            boards[idx].play_turn(guess.as_str(), code, false)?;
+        }
+        self.boards = boards;
+        Ok(self.status())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::words;
+    use crate::oracle::simulate_results;
+
+    fn multi() -> MultiSolver {
+        let words = words(&["break", "bread", "cream", "dream", "crane", "irate", "great", "treat", "slimy", "spoon"]);
+        let mut solver = WordleSolver::builder_from(words).build().unwrap();
+        solver.start();
+        MultiSolver::new(&solver, 2)
+    }
+
+    #[test]
+    fn every_board_gets_one_extra_guess() {
+        assert_eq!(multi().max_attempts(), 7);
+    }
+
+    #[test]
+    fn bad_results_apply_to_no_board() {
+        let mut multi = multi();
+        assert!(matches!(multi.apply("crane", &["BBBBB"]), Err(Error::ResultCount { results: 1, boards: 2 })));
+        assert!(matches!(multi.apply("crane", &["BBBBB", "BBGX"]), Err(Error::InvalidResults { .. })));
+        assert_eq!(multi.guesses(), 0);
+    }
+
+    #[test]
+    fn solved_boards_drop_out() {
+        let mut multi = multi();
+        let results = [simulate_results("break", "break"), simulate_results("break", "spoon")];
+        assert_eq!(multi.apply("break", &[&results[0], &results[1]]).unwrap(), GameStatus::Ongoing);
+        assert_eq!(multi.unsolved(), [1]);
+        // Boards only narrow their candidates; none picks a guess of its own
+        assert_eq!(multi.boards()[1].current_guess(), Some("break"));
+        // Only the spoon board is left, so the guess is one of its candidates
+        let guess = multi.make_guess().unwrap();
+        assert!(multi.boards()[1].candidates().contains(&guess), "{}", guess);
+        assert_eq!(multi.apply("spoon", &["GGGGG"]).unwrap(), GameStatus::Won);
+        assert!(matches!(multi.apply("spoon", &["GGGGG"]), Err(Error::GameOver)));
+    }
+}
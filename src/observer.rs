@@ -0,0 +1,24 @@
+//! Callbacks for following a game as it is played, without polling the solver.
+
+use crate::solver::{GameStatus, TurnRecord};
+
+/// Receives events from a [`WordleSolver`](crate::WordleSolver) it was added to with
+/// [`WordleSolver::add_observer`](crate::WordleSolver::add_observer).
+///
+/// Every method does nothing by default, so implementors only pick the events they
+/// care about. Observers are shared with `&self`; keep any state behind a `Mutex`
+/// or atomics. Headless play such as `play_games` runs on copies of the solver
+/// without observers, so simulated games never reach them.
+pub trait SolverObserver: Send + Sync {
+    /// The solver picked a guess to suggest for the next turn.
+    fn suggestion_computed(&self, _guess: &str) {}
+
+    /// Feedback was applied to a guess.
+    fn turn_applied(&self, _turn: &TurnRecord) {}
+
+    /// Later turns were taken back, leaving `turns_kept` applied; zero after a reset.
+    fn turns_undone(&self, _turns_kept: usize) {}
+
+    /// The game was won or lost after `attempts` guesses.
+    fn game_over(&self, _status: GameStatus, _attempts: usize) {}
+}
@@ -0,0 +1,396 @@
+//! The feedback oracle and the information measures built on it.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use crate::error::{Error, Result};
+use crate::par::*;
+
+/// The feedback Wordle gives for `guess` when the answer is `target`, as a string of
+/// 'G' (right letter, right place), 'Y' (in the word elsewhere) and 'B' (not in the word).
+pub fn simulate_results(guess: &str, target: &str) -> String {
+    let mut results = vec!['B'; guess.len()];
+    let mut target_count: HashMap<char, i32> = HashMap::new();
+
+    // Greens claim their letters first, so an earlier misplaced copy can't take a later exact match's letter
+    for (i, (g, t)) in guess.chars().zip(target.chars()).enumerate() {
+        if g == t {
+            results[i] = 'G';
+        } else {
+            *target_count.entry(t).or_insert(0) += 1;
+        }
+    }
+
+    for (i, g) in guess.chars().enumerate() {
+        if results[i] == 'B' && target_count.get(&g).unwrap_or(&0) > &0 {
+            results[i] = 'Y';
+            *target_count.get_mut(&g).unwrap() -= 1;
+        }
+    }
+
+    let mut result_string = String::with_capacity(guess.len());
+    result_string.extend(results.iter());
+    result_string
+}
+
+/// The feedback for `guess` against every word in `targets`, in the same order,
+/// computed in parallel.
+pub fn simulate_batch(guess: &str, targets: &[String]) -> Vec<Feedback> {
+    let mut feedback = Vec::with_capacity(targets.len());
+    simulate_batch_into(guess, targets, &mut feedback);
+    feedback
+}
+
+/// Like [`simulate_batch`], but replaces the contents of `out`, reusing its storage
+/// across calls.
+pub fn simulate_batch_into(guess: &str, targets: &[String], out: &mut Vec<Feedback>) {
+    out.clear();
+    out.par_extend(targets.par_iter().map(|target| Feedback::simulate(guess, target)));
+}
+
+/// Feedback for one guess: one 'G', 'Y' or 'B' per letter, checked when it is built
+/// so that holders never need to validate it again.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Feedback(String);
+
+impl Feedback {
+    /// Parses feedback for a word of `length` letters. Besides 'G', 'Y' and 'B' it
+    /// takes a row of squares pasted from a share grid (🟩 or 🟧 for green, 🟨 or 🟦
+    /// for yellow, ⬛ or ⬜ for gray) and the base-3 digits other tools use: '2' or
+    /// '+' for green, '1' or '?' for yellow, '0' or '-' for gray.
+    pub fn parse(results: &str, length: usize) -> Result<Self> {
+        let letters: String = results
+            .chars()
+            .filter(|&c| c != '\u{fe0f}')
+            .map(|c| match c {
+                '🟩' | '🟧' | '2' | '+' => 'G',
+                '🟨' | '🟦' | '1' | '?' => 'Y',
+                '⬛' | '⬜' | '0' | '-' => 'B',
+                c => c,
+            })
+            .collect();
+        match Self::try_from(letters.as_str()) {
+            Ok(feedback) if feedback.0.len() == length => Ok(feedback),
+            _ => Err(Error::InvalidResults { results: results.to_string(), length }),
+        }
+    }
+
+    /// The feedback for `guess` when the answer is `target`.
+    pub fn simulate(guess: &str, target: &str) -> Self {
+        Self(simulate_results(guess, target))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether every letter came back green.
+    pub fn is_solved(&self) -> bool {
+        self.0.chars().all(|c| c == 'G')
+    }
+}
+
+impl TryFrom<&str> for Feedback {
+    type Error = Error;
+
+    /// Accepts feedback of any length, as long as it is only 'G', 'Y' and 'B'.
+    fn try_from(results: &str) -> Result<Self> {
+        if results.is_empty() || !results.chars().all(|c| matches!(c, 'G' | 'Y' | 'B')) {
+            return Err(Error::InvalidResults { results: results.to_string(), length: results.chars().count() });
+        }
+        Ok(Self(results.to_string()))
+    }
+}
+
+impl FromStr for Feedback {
+    type Err = Error;
+
+    fn from_str(results: &str) -> Result<Self> {
+        Self::try_from(results)
+    }
+}
+
+impl AsRef<str> for Feedback {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Feedback packed into one number: a base-3 digit per letter, first letter most
+/// significant, with 0 for gray, 1 for yellow and 2 for green. Only
+/// [`parse_results_code`] and [`simulate_results_pattern`] build one, and
+/// [`TryFrom<u16>`] takes five-letter codes from other tools, so the value always fits
+/// its word length: below 243 for five letters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PatternCode(u16);
+
+impl PatternCode {
+    /// The longest word whose feedback fits in a code.
+    pub const MAX_LETTERS: usize = 10;
+
+    /// Number of distinct codes for five-letter words, the bound [`TryFrom<u16>`] checks.
+    pub const FIVE_LETTER_CODES: u16 = 243;
+
+    fn from_results(results: &str) -> Self {
+        Self(results.chars().fold(0, |code, c| code * 3 + DIGITS.iter().position(|&digit| digit == c).unwrap() as u16))
+    }
+
+    pub fn get(self) -> u16 {
+        self.0
+    }
+
+    /// The feedback as 'G', 'Y' and 'B' for a word of `length` letters.
+    pub fn results(self, length: usize) -> String {
+        let mut results: Vec<char> = (0..length)
+            .scan(self.0, |code, _| {
+                let digit = DIGITS[usize::from(*code % 3)];
+                *code /= 3;
+                Some(digit)
+            })
+            .collect();
+        results.reverse();
+        results.into_iter().collect()
+    }
+
+    /// Whether every letter of a `length`-letter word came back green.
+    pub fn is_solved(self, length: usize) -> bool {
+        u32::from(self.0) + 1 == 3u32.pow(length as u32)
+    }
+}
+
+// The feedback letter for each base-3 digit of a pattern code
+const DIGITS: [char; 3] = ['B', 'Y', 'G'];
+
+impl TryFrom<u16> for PatternCode {
+    type Error = Error;
+
+    /// Accepts the code of five-letter feedback, below [`PatternCode::FIVE_LETTER_CODES`].
+    fn try_from(code: u16) -> Result<Self> {
+        if code >= Self::FIVE_LETTER_CODES {
+            return Err(Error::InvalidResults { results: code.to_string(), length: 5 });
+        }
+        Ok(Self(code))
+    }
+}
+
+/// Parses feedback as [`Feedback::parse`] does, into its [`PatternCode`]. Words longer
+/// than [`PatternCode::MAX_LETTERS`] have no code.
+pub fn parse_results_code(results: &str, length: usize) -> Result<PatternCode> {
+    if length > PatternCode::MAX_LETTERS {
+        return Err(Error::InvalidResults { results: results.to_string(), length });
+    }
+    Feedback::parse(results, length).map(|feedback| PatternCode::from_results(feedback.as_str()))
+}
+
+/// The [`PatternCode`] of [`simulate_results`] for `guess` against `target`, which must
+/// be equally long and no longer than [`PatternCode::MAX_LETTERS`].
+pub fn simulate_results_pattern(guess: &str, target: &str) -> PatternCode {
+    debug_assert!(guess.len() <= PatternCode::MAX_LETTERS);
+    PatternCode::from_results(&simulate_results(guess, target))
+}
+
+/// Relative likelihood of each word being the answer; words without an entry weigh 1.
+pub type WordWeights = HashMap<String, f64>;
+
+/// The weight of `word`, 1 unless `weights` says otherwise.
+pub fn word_weight(weights: &WordWeights, word: &str) -> f64 {
+    weights.get(word).copied().unwrap_or(1.0)
+}
+
+/// The combined weight of every word in `word_list`.
+pub fn total_weight(word_list: &[String], weights: &WordWeights) -> f64 {
+    word_list.iter().map(|word| word_weight(weights, word)).sum()
+}
+
+/// Number of words and their combined weight for each feedback pattern `word` can produce.
+pub fn pattern_counts(word: &str, word_list: &[String], weights: &WordWeights) -> HashMap<String, (usize, f64)> {
+    word_list
+        .iter()
+        .map(|target| (simulate_results(word, target), word_weight(weights, target)))
+        .fold(HashMap::new(), |mut acc, (pattern, weight)| {
+            let bucket = acc.entry(pattern).or_insert((0, 0.0));
+            bucket.0 += 1;
+            bucket.1 += weight;
+            acc
+        })
+}
+
+/// Entropy in bits of the pattern distribution described by [`pattern_counts`].
+pub fn entropy_of_counts(pattern_counts: &HashMap<String, (usize, f64)>, total_weight: f64) -> f64 {
+    -pattern_counts.values().fold(0.0, |acc, &(_, weight)| {
+        let probability = weight / total_weight;
+        acc + probability * probability.log2()
+    })
+}
+
+/// Uncertainty about the answer, in bits, when it is drawn from `word_list` by weight.
+pub fn distribution_entropy(word_list: &[String], weights: &WordWeights) -> f64 {
+    let total_weight = total_weight(word_list, weights);
+    -word_list.iter().fold(0.0, |acc, word| {
+        let probability = word_weight(weights, word) / total_weight;
+        acc + probability * probability.log2()
+    })
+}
+
+/// Expected information, in bits, from guessing `word` when the answer is drawn from `word_list`.
+pub fn calculate_entropy(word: &str, word_list: &[String], weights: &WordWeights) -> f64 {
+    entropy_of_counts(&pattern_counts(word, word_list, weights), total_weight(word_list, weights))
+}
+
+/// How well a guess splits the candidates.
+#[derive(Clone, Debug)]
+pub struct GuessScore {
+    pub word: String,
+    /// Expected information in bits.
+    pub entropy: f64,
+    /// Size of the largest feedback bucket, i.e. candidates left after the unluckiest result.
+    pub worst_case: usize,
+}
+
+/// Scores `word` as a guess against the candidates in `word_list`.
+pub fn score_guess(word: &str, word_list: &[String], weights: &WordWeights) -> GuessScore {
+    let counts = pattern_counts(word, word_list, weights);
+    GuessScore {
+        word: word.to_string(),
+        entropy: entropy_of_counts(&counts, total_weight(word_list, weights)),
+        worst_case: counts.values().map(|&(count, _)| count).max().unwrap_or(0),
+    }
+}
+
+/// Groups the words in `word_list` by the feedback `guess` would get if each were the answer.
+pub fn partition_by_pattern(guess: &str, word_list: &[String]) -> HashMap<String, Vec<String>> {
+    word_list.iter().fold(HashMap::new(), |mut acc, target| {
+        acc.entry(simulate_results(guess, target)).or_default().push(target.clone());
+        acc
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+
+    #[test]
+    fn scores_carry_entropy_and_the_worst_case_bucket() {
+        let words = test_util::words(&["fight", "light", "might", "sight", "crane"]);
+        let weights = WordWeights::default();
+        // Crane shares no letter with any _ight word, so those four share one bucket
+        let score = score_guess("crane", &words, &weights);
+        assert_eq!(score.worst_case, 4);
+        // One in five alone and four in five together
+        let expected = -(0.2f64 * 0.2f64.log2() + 0.8 * 0.8f64.log2());
+        assert!((score.entropy - expected).abs() < 1e-9);
+        assert!((calculate_entropy("crane", &words, &weights) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn partition_groups_words_by_their_feedback() {
+        let words = test_util::words(&["break", "bread", "cream", "slimy"]);
+        let partition = partition_by_pattern("bread", &words);
+        assert_eq!(partition.len(), 4);
+        assert_eq!(partition["GGGGG"], ["bread"]);
+        assert_eq!(partition["GGGGB"], ["break"]);
+        assert_eq!(partition["BBBBB"], ["slimy"]);
+        // Every word lands in exactly one bucket
+        assert_eq!(partition.values().map(Vec::len).sum::<usize>(), words.len());
+        let counts = pattern_counts("bread", &words, &WordWeights::default());
+        assert!(partition.iter().all(|(pattern, bucket)| counts[pattern].0 == bucket.len()));
+    }
+
+    #[test]
+    fn duplicate_letters_are_marked_once_per_copy_in_the_answer() {
+        let cases = [
+            // One E in the answer: the first guessed E takes it, the second is gray
+            ("speed", "abide", "BBYBY"),
+            // The last E is green, so only one E is left over for the yellow
+            ("eerie", "there", "YBYBG"),
+            // Both guessed Ls are yellow since the answer holds two
+            ("llama", "hello", "YYBBB"),
+            ("hello", "llama", "BBYYB"),
+        ];
+        for (guess, target, expected) in cases {
+            assert_eq!(simulate_results(guess, target), expected, "{} against {}", guess, target);
+        }
+    }
+
+    #[test]
+    fn feedback_is_read_from_every_alphabet() {
+        let cases = [
+            ("GYBBG", "GYBBG"),
+            // Shared grids, in normal and high-contrast colors, in dark and light mode
+            ("🟩🟨⬛⬛🟩", "GYBBG"),
+            ("🟧🟦⬜⬜🟧", "GYBBG"),
+            // Some platforms paste the squares with a variation selector after each
+            ("🟩\u{fe0f}🟨\u{fe0f}⬛\u{fe0f}⬛\u{fe0f}🟩\u{fe0f}", "GYBBG"),
+            ("⬜\u{fe0f}⬜\u{fe0f}🟧🟧🟦", "BBGGY"),
+            ("21002", "GYBBG"),
+            ("+?--+", "GYBBG"),
+            // Alphabets can be mixed within one row
+            ("🟩1-B+", "GYBBG"),
+            ("2🟦⬛0🟧", "GYBBG"),
+        ];
+        for (results, expected) in cases {
+            let feedback = Feedback::parse(results, 5).unwrap_or_else(|e| panic!("{:?}: {}", results, e));
+            assert_eq!(feedback.as_str(), expected, "{:?}", results);
+        }
+    }
+
+    #[test]
+    fn feedback_of_the_wrong_length_or_with_other_characters_is_refused() {
+        let cases = [
+            ("", 5),
+            ("GYBB", 5),
+            ("GYBBGG", 5),
+            ("🟩🟨⬛⬛", 5),
+            ("210022", 5),
+            ("GYBBG", 6),
+            ("GYBXG", 5),
+            ("3YBBG", 5),
+        ];
+        for (results, length) in cases {
+            assert!(
+                matches!(Feedback::parse(results, length), Err(Error::InvalidResults { length: l, .. }) if l == length),
+                "{:?} as {} letters",
+                results,
+                length
+            );
+        }
+        assert_eq!(Feedback::parse("GYBBG", 5).unwrap().as_str(), "GYBBG");
+        assert!(Feedback::simulate("break", "break").is_solved());
+    }
+
+    #[test]
+    fn pattern_codes_pack_feedback_in_base_three() {
+        let code = parse_results_code("🟩1-B+", 5).unwrap();
+        assert_eq!(code.get(), 2 * 81 + 27 + 2);
+        assert_eq!(code.results(5), "GYBBG");
+        assert_eq!(simulate_results_pattern("speed", "abide"), parse_results_code("BBYBY", 5).unwrap());
+        assert!(simulate_results_pattern("break", "break").is_solved(5));
+        assert!(!parse_results_code("GGGG", 4).unwrap().is_solved(5));
+
+        assert_eq!(PatternCode::try_from(242).unwrap().results(5), "GGGGG");
+        assert!(matches!(PatternCode::try_from(243), Err(Error::InvalidResults { length: 5, .. })));
+        assert!(parse_results_code("GYBBG", 6).is_err());
+        assert!(parse_results_code(&"G".repeat(11), 11).is_err());
+        assert_eq!(parse_results_code(&"G".repeat(10), 10).unwrap().get(), 59048);
+    }
+
+    #[test]
+    fn batch_feedback_matches_one_at_a_time() {
+        let targets = test_util::words(&["break", "bread", "cream", "slimy"]);
+        let feedback = simulate_batch("bread", &targets);
+        let expected: Vec<String> = targets.iter().map(|target| simulate_results("bread", target)).collect();
+        assert_eq!(feedback.iter().map(Feedback::as_str).collect::<Vec<_>>(), expected);
+
+        // Reused storage holds only the latest batch
+        let mut out = feedback;
+        simulate_batch_into("slimy", &targets[..2], &mut out);
+        assert_eq!(out, [Feedback::simulate("slimy", "break"), Feedback::simulate("slimy", "bread")]);
+    }
+}
@@ -0,0 +1,62 @@
+//! Parallel iteration through rayon when the `parallel` feature is on, and the same
+//! method names over plain iterators when it is off, so callers don't need to care.
+
+use crate::error::{Error, Result};
+
+#[cfg(feature = "parallel")]
+pub(crate) use rayon::prelude::*;
+
+#[cfg(not(feature = "parallel"))]
+pub(crate) use self::sequential::*;
+
+#[cfg(not(feature = "parallel"))]
+mod sequential {
+    pub(crate) trait ParallelSlice<T> {
+        fn par_iter(&self) -> std::slice::Iter<'_, T>;
+    }
+
+    impl<T> ParallelSlice<T> for [T] {
+        fn par_iter(&self) -> std::slice::Iter<'_, T> {
+            self.iter()
+        }
+    }
+
+    pub(crate) trait IntoParallelIterator: IntoIterator + Sized {
+        fn into_par_iter(self) -> Self::IntoIter {
+            self.into_iter()
+        }
+    }
+
+    impl<I: IntoIterator> IntoParallelIterator for I {}
+
+    pub(crate) trait ParallelExtend<T> {
+        fn par_extend<I: IntoIterator<Item = T>>(&mut self, items: I);
+    }
+
+    impl<T> ParallelExtend<T> for Vec<T> {
+        fn par_extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+            self.extend(items);
+        }
+    }
+}
+
+/// Makes the global pool run `threads` threads. Rayon's pool is process-wide, so this
+/// fails if it was already started with a different count.
+#[cfg(feature = "parallel")]
+pub(crate) fn set_thread_count(threads: usize) -> Result<()> {
+    let built = rayon::ThreadPoolBuilder::new().num_threads(threads).build_global();
+    let running = rayon::current_num_threads();
+    if built.is_err() && running != threads {
+        return Err(Error::InvalidSettings(format!("the thread pool already runs {} threads", running)));
+    }
+    Ok(())
+}
+
+/// Without the `parallel` feature everything runs on the calling thread.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn set_thread_count(threads: usize) -> Result<()> {
+    if threads != 1 {
+        return Err(Error::InvalidSettings("built without the parallel feature, so only 1 thread can run".to_string()));
+    }
+    Ok(())
+}
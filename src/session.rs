@@ -0,0 +1,218 @@
+//! Saved games and transcripts: enough to rebuild a game in progress, or re-drive
+//! a finished one, on top of the same word list.
+//!
+//! A session file is plain text, one `key value` pair per line after a header:
+//!
+//! ```text
+//! wordle-solver session
+//! words 508 9f1c0e3a5b7d2468
+//! length 5
+//! hard-mode false
+//! max-attempts 6
+//! turn irate BYBBG
+//! turn horse BBYBG
+//! guess shore
+//! ```
+//!
+//! A transcript is meant for bug reports: `#` lines describe the game, then each
+//! turn is one tab-separated line of turn number, suggested guess (`-` if
+//! unknown), played guess, results, candidates left and Unix time.
+//!
+//! ```text
+//! # wordle-solver transcript
+//! # words 508 9f1c0e3a5b7d2468
+//! # length 5
+//! # strategy entropy
+//! # turn  suggested  played  results  left  time
+//! 1  irate  irate  BYBBG  8  1760630400
+//! 2  horse  shore  BBYBG  2  1760630412
+//! ```
+
+use std::fs;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::error::{Error, Result};
+
+const HEADER: &str = "wordle-solver session";
+const TRANSCRIPT_HEADER: &str = "# wordle-solver transcript";
+
+/// A game as written to disk.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SavedSession {
+    pub word_count: usize,
+    pub fingerprint: u64,
+    pub word_length: usize,
+    pub hard_mode: bool,
+    pub max_attempts: usize,
+    /// Each applied guess with its feedback, oldest first
+    pub turns: Vec<(String, String)>,
+    pub current_guess: Option<String>,
+}
+
+/// FNV-1a over the words in order, so a session can tell whether it is being
+/// resumed against the word list it was saved with.
+pub(crate) fn word_list_fingerprint(words: &[String]) -> u64 {
+    words.iter().flat_map(|word| word.bytes().chain(Some(b'\n'))).fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+impl SavedSession {
+    pub fn write(&self, path: &str) -> Result<()> {
+        let mut text = format!("{}\n", HEADER);
+        text.push_str(&format!("words {} {:016x}\n", self.word_count, self.fingerprint));
+        text.push_str(&format!("length {}\n", self.word_length));
+        text.push_str(&format!("hard-mode {}\n", self.hard_mode));
+        text.push_str(&format!("max-attempts {}\n", self.max_attempts));
+        for (guess, results) in &self.turns {
+            text.push_str(&format!("turn {} {}\n", guess, results));
+        }
+        if let Some(guess) = &self.current_guess {
+            text.push_str(&format!("guess {}\n", guess));
+        }
+        fs::write(path, text).map_err(|source| Error::Io { path: path.to_string(), source })
+    }
+
+    pub fn read(path: &str) -> Result<Self> {
+        let text = fs::read_to_string(path).map_err(|source| Error::Io { path: path.to_string(), source })?;
+        let invalid = |reason: String| Error::InvalidSession { path: path.to_string(), reason };
+
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+        if lines.next() != Some(HEADER) {
+            return Err(invalid("not a saved session".to_string()));
+        }
+
+        let mut words = None;
+        let mut word_length = None;
+        let mut hard_mode = None;
+        let mut max_attempts = None;
+        let mut turns = Vec::new();
+        let mut current_guess = None;
+        for line in lines {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields.as_slice() {
+                ["words", count, fingerprint] => {
+                    let count = count.parse::<usize>().ok();
+                    let fingerprint = u64::from_str_radix(fingerprint, 16).ok();
+                    words = count.zip(fingerprint);
+                }
+                ["length", length] => word_length = length.parse::<usize>().ok(),
+                ["hard-mode", hard] => hard_mode = hard.parse::<bool>().ok(),
+                ["max-attempts", attempts] => max_attempts = attempts.parse::<usize>().ok(),
+                ["turn", guess, results] => turns.push((guess.to_string(), results.to_string())),
+                ["guess", guess] => current_guess = Some(guess.to_string()),
+                _ => return Err(invalid(format!("unreadable line '{}'", line))),
+            }
+        }
+
+        let (word_count, fingerprint) = words.ok_or_else(|| invalid("missing word list identity".to_string()))?;
+        Ok(Self {
+            word_count,
+            fingerprint,
+            word_length: word_length.ok_or_else(|| invalid("missing word length".to_string()))?,
+            hard_mode: hard_mode.ok_or_else(|| invalid("missing hard mode setting".to_string()))?,
+            max_attempts: max_attempts.ok_or_else(|| invalid("missing max attempts".to_string()))?,
+            turns,
+            current_guess,
+        })
+    }
+}
+
+/// A played turn as written to a transcript.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct TranscriptTurn {
+    pub suggested: Option<String>,
+    pub guess: String,
+    pub results: String,
+    pub candidates_after: usize,
+    pub applied_at: SystemTime,
+}
+
+/// A game's turns with what the solver suggested at each.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Transcript {
+    pub word_count: usize,
+    pub fingerprint: u64,
+    pub word_length: usize,
+    /// Label of the configured strategy, for the reader only
+    pub strategy: String,
+    pub turns: Vec<TranscriptTurn>,
+}
+
+impl Transcript {
+    pub fn write(&self, path: &str) -> Result<()> {
+        let mut text = format!("{}\n", TRANSCRIPT_HEADER);
+        text.push_str(&format!("# words {} {:016x}\n", self.word_count, self.fingerprint));
+        text.push_str(&format!("# length {}\n", self.word_length));
+        text.push_str(&format!("# strategy {}\n", self.strategy));
+        text.push_str("# turn\tsuggested\tplayed\tresults\tleft\ttime\n");
+        for (idx, turn) in self.turns.iter().enumerate() {
+            let time = turn.applied_at.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+            text.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\n",
+                idx + 1,
+                turn.suggested.as_deref().unwrap_or("-"),
+                turn.guess,
+                turn.results,
+                turn.candidates_after,
+                time
+            ));
+        }
+        fs::write(path, text).map_err(|source| Error::Io { path: path.to_string(), source })
+    }
+
+    pub fn read(path: &str) -> Result<Self> {
+        let text = fs::read_to_string(path).map_err(|source| Error::Io { path: path.to_string(), source })?;
+        let invalid = |reason: String| Error::InvalidSession { path: path.to_string(), reason };
+
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+        if lines.next() != Some(TRANSCRIPT_HEADER) {
+            return Err(invalid("not a transcript".to_string()));
+        }
+
+        let mut words = None;
+        let mut word_length = None;
+        let mut strategy = String::new();
+        let mut turns = Vec::new();
+        for line in lines {
+            if let Some(comment) = line.strip_prefix('#') {
+                let fields: Vec<&str> = comment.split_whitespace().collect();
+                match fields.as_slice() {
+                    ["words", count, fingerprint] => {
+                        let count = count.parse::<usize>().ok();
+                        let fingerprint = u64::from_str_radix(fingerprint, 16).ok();
+                        words = count.zip(fingerprint);
+                    }
+                    ["length", length] => word_length = length.parse::<usize>().ok(),
+                    ["strategy", ..] => strategy = fields[1..].join(" "),
+                    _ => {}
+                }
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split('\t').collect();
+            let turn = match fields.as_slice() {
+                [_, suggested, guess, results, left, time] => left.parse::<usize>().ok().zip(time.parse::<u64>().ok()).map(
+                    |(candidates_after, time)| TranscriptTurn {
+                        suggested: Some(suggested.to_string()).filter(|suggested| suggested != "-"),
+                        guess: guess.to_string(),
+                        results: results.to_string(),
+                        candidates_after,
+                        applied_at: UNIX_EPOCH + Duration::from_secs(time),
+                    },
+                ),
+                _ => None,
+            };
+            turns.push(turn.ok_or_else(|| invalid(format!("unreadable line '{}'", line)))?);
+        }
+
+        let (word_count, fingerprint) = words.ok_or_else(|| invalid("missing word list identity".to_string()))?;
+        Ok(Self {
+            word_count,
+            fingerprint,
+            word_length: word_length.ok_or_else(|| invalid("missing word length".to_string()))?,
+            strategy,
+            turns,
+        })
+    }
+}
+
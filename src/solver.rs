@@ -0,0 +1,1637 @@
+//! The solver: game state, constraint tracking and guess selection.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::SystemTime;
+use crate::cancel::CancelToken;
+use crate::clock::{Clock, SystemClock};
+use crate::constraints::Constraints;
+use crate::engine::SolverEngine;
+use crate::error::{Error, Result, TurnConflict};
+use crate::load::{common_word_weights, valid_word, FileSource, Word, WordSource, DEFAULT_WORD_LENGTH};
+use crate::observer::SolverObserver;
+use crate::oracle::{
+    distribution_entropy, entropy_of_counts, parse_results_code, pattern_counts, partition_by_pattern, score_guess,
+    simulate_results, simulate_results_pattern, total_weight, word_weight, Feedback, GuessScore, PatternCode, WordWeights,
+};
+use crate::par::*;
+use crate::session::{word_list_fingerprint, SavedSession, Transcript, TranscriptTurn};
+use crate::strategy::{
+    best_entropy_guess, expected_guesses, BeamSettings, PhaseThresholds, ProbePolicy, Strategy, TiePolicy, EXPECTATION_DEPTH,
+};
+
+// The game as it stood just before a turn's feedback was applied
+#[derive(Clone)]
+struct Snapshot {
+    word_list: Vec<String>,
+    // Only kept in hard mode, the one time the guess list narrows
+    guess_list: Option<Vec<String>>,
+    constraints: Constraints,
+    attempts: usize,
+    guess: String,
+    suggested_guess: Option<String>,
+}
+
+// Best follow-up to a fixed opener for each feedback pattern it has produced so far
+#[derive(Clone)]
+struct SecondGuessBook {
+    opener: String,
+    follow_ups: HashMap<String, String>,
+}
+
+/// Candidates that agree everywhere except one position, like batch/catch/hatch.
+#[derive(Clone, Debug)]
+pub struct CandidateFamily {
+    /// The shared letters with '_' at the differing position
+    pub pattern: String,
+    pub words: Vec<String>,
+}
+
+/// A candidate ruled out by a turn, with the feedback it would have produced instead.
+#[derive(Clone, Debug)]
+pub struct Elimination {
+    pub word: String,
+    /// What the guess would have shown had this word been the answer
+    pub pattern: String,
+}
+
+/// What one applied guess taught the solver, all information in bits.
+#[derive(Clone, Debug)]
+pub struct TurnRecord {
+    pub guess: String,
+    /// What the solver suggested for this turn, if known; differs from `guess` when
+    /// another guess was chosen instead
+    pub suggested: Option<String>,
+    pub results: String,
+    pub applied_at: SystemTime,
+    pub candidates_after: usize,
+    pub entropy_before: f64,
+    pub entropy_after: f64,
+    pub expected_information: f64,
+    /// None when the results ruled out every candidate
+    pub actual_information: Option<f64>,
+}
+
+/// One turn of a replayed transcript, pairing the recorded suggestion with the
+/// one the solver makes now.
+#[derive(Clone, Debug)]
+pub struct ReplayStep {
+    pub guess: String,
+    pub recorded: Option<String>,
+    pub suggested: Option<String>,
+}
+
+impl ReplayStep {
+    /// Whether the solver still suggests what the transcript recorded.
+    pub fn matches(&self) -> bool {
+        self.recorded.is_none() || self.recorded == self.suggested
+    }
+}
+
+/// Outcome of one headless game against a known secret.
+#[derive(Clone, Debug)]
+pub struct GameRecord {
+    pub secret: String,
+    pub guesses: Vec<String>,
+    pub won: bool,
+}
+
+impl GameRecord {
+    /// Guesses played, counting every guess of a lost game.
+    pub fn guess_count(&self) -> usize {
+        self.guesses.len()
+    }
+}
+
+/// Totals over a batch of headless games.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchSummary {
+    pub games: usize,
+    /// Mean guesses per game, counting every guess of a lost game
+    pub average: f64,
+    /// Games won in 1, 2, ... `max_attempts` guesses
+    pub distribution: Vec<usize>,
+    /// Secrets of the games that were lost, in the order played
+    pub failures: Vec<String>,
+}
+
+impl BatchSummary {
+    pub fn from_records(records: &[GameRecord], max_attempts: usize) -> Self {
+        let total_guesses: usize = records.iter().map(GameRecord::guess_count).sum();
+        let mut distribution = vec![0; max_attempts];
+        for record in records.iter().filter(|record| record.won) {
+            if let Some(games) = distribution.get_mut(record.guess_count().saturating_sub(1)) {
+                *games += 1;
+            }
+        }
+        Self {
+            games: records.len(),
+            average: total_guesses as f64 / records.len().max(1) as f64,
+            distribution,
+            failures: records.iter().filter(|record| !record.won).map(|record| record.secret.clone()).collect(),
+        }
+    }
+}
+
+/// Where a game stands after a turn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameStatus {
+    Won,
+    Lost,
+    Ongoing,
+}
+
+/// Smallest group worth reporting as a family.
+pub const MIN_FAMILY_SIZE: usize = 3;
+
+/// A game in progress: the remaining candidates, what the feedback so far has
+/// ruled out, and the settings that decide each guess.
+///
+/// Settings are public fields and may be changed between turns; the game state
+/// is read through accessors and only advanced by [`WordleSolver::next_turn`].
+#[derive(Clone)]
+pub struct WordleSolver {
+    word_length: usize,
+    // Every word loaded from the word list, before any filtering
+    full_word_list: Vec<String>,
+    word_list: Vec<String>,
+    // Words allowed as guesses when they differ from the possible answers
+    guess_list: Option<Vec<String>>,
+    // The guess list as loaded, before hard mode narrows it
+    full_guess_list: Option<Vec<String>>,
+    word_weights: WordWeights,
+    constraints: Constraints,
+    attempts: usize,
+    /// Guesses allowed before the game is lost
+    pub max_attempts: usize,
+    current_guess: Option<String>,
+    // The solver's own pick for the current turn, kept when another guess is chosen
+    suggested_guess: Option<String>,
+    turns: Vec<TurnRecord>,
+    // One snapshot per turn, so undo restores state instead of replaying
+    snapshots: Vec<Snapshot>,
+    // Simulated games skip snapshots, since they never undo
+    keep_snapshots: bool,
+    /// Strategy used whenever no phase or exact threshold applies
+    pub strategy: Strategy,
+    /// Width and depth for [`Strategy::Beam`]
+    pub beam: BeamSettings,
+    /// Seeds [`Strategy::Rollout`]'s random play so the same game picks the same guesses
+    pub seed: Option<u64>,
+    /// When set, the strategy only applies until the candidates drop below these counts
+    pub phase_thresholds: Option<PhaseThresholds>,
+    /// When set, play the candidate with the fewest expected guesses below this many
+    /// candidates. Probes are never weighed, and the search is exponential, so keep it small.
+    pub exact_below: Option<usize>,
+    /// Entropies closer than this to the best count as tied for best
+    pub tie_epsilon: f64,
+    pub tie_policy: TiePolicy,
+    /// When guesses that can't be the answer may be suggested
+    pub probe_policy: ProbePolicy,
+    /// When set, every game opens with this guess instead of the strategy's pick
+    pub opener: Option<String>,
+    // Every guess must keep the greens in place and reuse the yellows
+    hard_mode: bool,
+    second_guess_book: Option<SecondGuessBook>,
+    observers: Vec<Arc<dyn SolverObserver>>,
+    // Stamps each applied turn
+    clock: Arc<dyn Clock>,
+    cancel: CancelToken,
+}
+
+/// Configures and loads a [`WordleSolver`], rejecting settings that can't work together.
+#[derive(Clone, Debug)]
+pub struct WordleSolverBuilder {
+    answers: Arc<dyn WordSource>,
+    guesses: Option<Arc<dyn WordSource>>,
+    word_length: usize,
+    max_attempts: usize,
+    strategy: Strategy,
+    beam: BeamSettings,
+    seed: Option<u64>,
+    phase_thresholds: Option<PhaseThresholds>,
+    exact_below: Option<usize>,
+    tie_epsilon: f64,
+    tie_policy: TiePolicy,
+    probe_policy: ProbePolicy,
+    opener: Option<String>,
+    hard_mode: bool,
+    common_bias: Option<f64>,
+    priors: WordWeights,
+    threads: Option<usize>,
+    clock: Arc<dyn Clock>,
+}
+
+impl WordleSolverBuilder {
+    fn new(answers: Arc<dyn WordSource>) -> Self {
+        Self {
+            answers,
+            guesses: None,
+            word_length: DEFAULT_WORD_LENGTH,
+            max_attempts: 6,
+            strategy: Strategy::Entropy,
+            beam: BeamSettings::default(),
+            seed: None,
+            phase_thresholds: None,
+            exact_below: None,
+            tie_epsilon: 1e-10,
+            tie_policy: TiePolicy::First,
+            probe_policy: ProbePolicy::NotLast,
+            opener: None,
+            hard_mode: false,
+            common_bias: None,
+            priors: WordWeights::new(),
+            threads: None,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Allows the words in `path` as guesses without making them possible answers.
+    pub fn guesses(self, path: &str) -> Self {
+        self.guesses_from(FileSource(path.to_string()))
+    }
+
+    /// Allows the words from `source` as guesses without making them possible answers.
+    pub fn guesses_from(mut self, source: impl WordSource + 'static) -> Self {
+        self.guesses = Some(Arc::new(source));
+        self
+    }
+
+    /// Plays words of `length` letters, at most [`PatternCode::MAX_LETTERS`]; words of any
+    /// other length are skipped when loading.
+    pub fn word_length(mut self, length: usize) -> Self {
+        self.word_length = length;
+        self
+    }
+
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    pub fn strategy(mut self, strategy: Strategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    pub fn beam(mut self, beam: BeamSettings) -> Self {
+        self.beam = beam;
+        self
+    }
+
+    /// Makes random play reproducible: the same seed, word list and feedback give the same guesses.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Hands over to expected-guess and then worst-case search as the candidates dwindle.
+    pub fn phase_thresholds(mut self, thresholds: PhaseThresholds) -> Self {
+        self.phase_thresholds = Some(thresholds);
+        self
+    }
+
+    /// Below `count` candidates, plays the candidate with the fewest expected guesses,
+    /// found by exhaustive search. Probes are never weighed, and the search grows
+    /// exponentially with `count`, so keep it to a couple of dozen at most.
+    pub fn exact_below(mut self, count: usize) -> Self {
+        self.exact_below = Some(count);
+        self
+    }
+
+    pub fn tie_epsilon(mut self, epsilon: f64) -> Self {
+        self.tie_epsilon = epsilon;
+        self
+    }
+
+    pub fn tie_policy(mut self, policy: TiePolicy) -> Self {
+        self.tie_policy = policy;
+        self
+    }
+
+    /// When guesses that can't be the answer may be suggested; by default, on any turn but the last.
+    pub fn probe_policy(mut self, policy: ProbePolicy) -> Self {
+        self.probe_policy = policy;
+        self
+    }
+
+    /// Always opens with `word`; later turns are still scored by the strategy.
+    pub fn opener(mut self, word: &str) -> Self {
+        self.opener = Some(word.to_string());
+        self
+    }
+
+    /// Only guesses that keep every green in place and reuse every yellow.
+    pub fn hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    /// Makes bundled common words likelier to be the answer than other words, by `factor`
+    /// times their frequency weight, so more frequent words gain more.
+    pub fn common_bias(mut self, factor: f64) -> Self {
+        self.common_bias = Some(factor);
+        self
+    }
+
+    /// Multiplies the weight of each listed word, on top of any weight from the word list.
+    pub fn priors(mut self, priors: WordWeights) -> Self {
+        self.priors = priors;
+        self
+    }
+
+    /// Runs parallel scoring on this many threads. Rayon's pool is process-wide, so
+    /// this only takes effect for the first solver built with it. Without the
+    /// `parallel` feature only a single thread is accepted.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    /// Takes turn timestamps from `clock` instead of the system time.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        let invalid = |reason: &str| Err(Error::InvalidSettings(reason.to_string()));
+        if self.word_length == 0 {
+            return invalid("word length must be at least 1");
+        }
+        if self.word_length > PatternCode::MAX_LETTERS {
+            return invalid(&format!("word length must be at most {}", PatternCode::MAX_LETTERS));
+        }
+        if self.max_attempts == 0 {
+            return invalid("max attempts must be at least 1");
+        }
+        if self.strategy == Strategy::Beam && (self.beam.width == 0 || self.beam.depth == 0) {
+            return invalid("beam search needs a width and depth of at least 1");
+        }
+        if let Some(thresholds) = self.phase_thresholds {
+            if thresholds.guarantee_below > thresholds.expected_below {
+                return invalid("the guarantee threshold must not exceed the expected-guess threshold");
+            }
+        }
+        if self.tie_epsilon.is_nan() || self.tie_epsilon < 0.0 {
+            return invalid("tie epsilon must not be negative");
+        }
+        if self.common_bias.is_some_and(|factor| !(factor.is_finite() && factor > 0.0)) {
+            return invalid("common-word bias must be a positive number");
+        }
+        if self.priors.values().any(|weight| !(weight.is_finite() && *weight > 0.0)) {
+            return invalid("priors must be positive numbers");
+        }
+        if self.threads == Some(0) {
+            return invalid("thread count must be at least 1");
+        }
+        Ok(())
+    }
+
+    /// Checks the settings, then loads the word lists.
+    pub fn build(self) -> Result<WordleSolver> {
+        self.validate()?;
+        if let Some(threads) = self.threads {
+            set_thread_count(threads)?;
+        }
+
+        let mut solver = WordleSolver::load(self.answers.as_ref(), self.word_length)?;
+        solver.max_attempts = self.max_attempts;
+        solver.strategy = self.strategy;
+        solver.beam = self.beam;
+        solver.seed = self.seed;
+        solver.phase_thresholds = self.phase_thresholds;
+        solver.exact_below = self.exact_below;
+        solver.tie_epsilon = self.tie_epsilon;
+        solver.tie_policy = self.tie_policy;
+        solver.probe_policy = self.probe_policy;
+        solver.opener = match &self.opener {
+            Some(opener) => Some(Word::parse(opener, self.word_length)?.into_string()),
+            None => None,
+        };
+        solver.hard_mode = self.hard_mode;
+        solver.clock = self.clock;
+        for (word, prior) in self.priors {
+            *solver.word_weights.entry(word).or_insert(1.0) *= prior;
+        }
+        if let Some(factor) = self.common_bias {
+            solver.apply_common_word_bias(factor);
+        }
+        if let Some(guesses) = &self.guesses {
+            solver.load_guess_list(guesses.as_ref())?;
+        }
+        Ok(solver)
+    }
+}
+
+impl WordleSolver {
+    /// Starts a game over the five-letter words in `word_list_path`, in the format
+    /// read by [`load_word_list`](crate::load::load_word_list), with the default settings.
+    pub fn new(word_list_path: &str) -> Result<Self> {
+        Self::builder(word_list_path).build()
+    }
+
+    /// Starts configuring a game whose possible answers are the words in `answers_path`.
+    pub fn builder(answers_path: &str) -> WordleSolverBuilder {
+        Self::builder_from(FileSource(answers_path.to_string()))
+    }
+
+    /// Starts configuring a game whose possible answers come from `source`.
+    pub fn builder_from(source: impl WordSource + 'static) -> WordleSolverBuilder {
+        WordleSolverBuilder::new(Arc::new(source))
+    }
+
+    fn load(source: &dyn WordSource, word_length: usize) -> Result<Self> {
+        let mut word_list = Vec::new();
+        let mut word_weights = WordWeights::new();
+        for (word, weight) in source.words(word_length)? {
+            if weight != 1.0 {
+                word_weights.insert(word.clone(), weight);
+            }
+            word_list.push(word);
+        }
+        if word_list.is_empty() {
+            return Err(Error::NoWords { path: source.name(), length: word_length });
+        }
+
+        Ok(Self {
+            word_length,
+            full_word_list: word_list.clone(),
+            word_list,
+            guess_list: None,
+            full_guess_list: None,
+            word_weights,
+            constraints: Constraints::new(word_length),
+            attempts: 0,
+            max_attempts: 6,
+            current_guess: None,
+            suggested_guess: None,
+            turns: Vec::new(),
+            snapshots: Vec::new(),
+            keep_snapshots: true,
+            strategy: Strategy::Entropy,
+            beam: BeamSettings::default(),
+            seed: None,
+            phase_thresholds: None,
+            exact_below: None,
+            tie_epsilon: 1e-10,
+            tie_policy: TiePolicy::First,
+            probe_policy: ProbePolicy::NotLast,
+            opener: None,
+            hard_mode: false,
+            second_guess_book: None,
+            observers: Vec::new(),
+            clock: Arc::new(SystemClock),
+            cancel: CancelToken::new(),
+        })
+    }
+
+    fn load_guess_list(&mut self, source: &dyn WordSource) -> Result<()> {
+        // Every possible answer is also a legal guess
+        let mut guess_list: Vec<String> = source.words(self.word_length)?.into_iter().map(|(word, _)| word).collect();
+        guess_list.extend(self.word_list.iter().cloned());
+        guess_list.sort();
+        guess_list.dedup();
+        self.full_guess_list = Some(guess_list.clone());
+        self.guess_list = Some(guess_list);
+        Ok(())
+    }
+
+    fn apply_common_word_bias(&mut self, factor: f64) {
+        let common_words = common_word_weights();
+        for word in &self.full_word_list {
+            if let Some(frequency) = common_words.get(word) {
+                *self.word_weights.entry(word.clone()).or_insert(1.0) *= factor * frequency;
+            }
+        }
+    }
+
+    /// Number of letters in every word of this game.
+    pub fn word_length(&self) -> usize {
+        self.word_length
+    }
+
+    /// Whether guesses must keep the greens in place and reuse the yellows.
+    pub fn hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    /// Words still possible as the answer.
+    pub fn candidates(&self) -> &[String] {
+        &self.word_list
+    }
+
+    /// Likelihood weights for words that have one; every other word weighs 1.
+    pub fn weights(&self) -> &WordWeights {
+        &self.word_weights
+    }
+
+    /// Number of guesses already applied.
+    pub fn attempts(&self) -> usize {
+        self.attempts
+    }
+
+    /// The guess the solver is waiting on feedback for.
+    pub fn current_guess(&self) -> Option<&str> {
+        self.current_guess.as_deref()
+    }
+
+    /// What the feedback so far has established about the answer.
+    pub fn constraints(&self) -> &Constraints {
+        &self.constraints
+    }
+
+    /// The candidates the last turn ruled out, in word list order, each with the
+    /// feedback it would have given. Empty before the first turn.
+    pub fn last_eliminated(&self) -> Vec<Elimination> {
+        let (snapshot, turn) = match (self.snapshots.last(), self.turns.last()) {
+            (Some(snapshot), Some(turn)) => (snapshot, turn),
+            _ => return Vec::new(),
+        };
+        let remaining: HashSet<&str> = self.word_list.iter().map(String::as_str).collect();
+        snapshot
+            .word_list
+            .iter()
+            .filter(|word| !remaining.contains(word.as_str()))
+            .map(|word| Elimination { word: word.clone(), pattern: simulate_results(&turn.guess, word) })
+            .collect()
+    }
+
+    /// Every word the game accepts as a guess: the extra guess list, if any, and every answer.
+    pub fn allowed_guesses(&self) -> &[String] {
+        self.full_guess_list.as_deref().unwrap_or(&self.full_word_list)
+    }
+
+    /// Every guess applied so far, oldest first.
+    pub fn turns(&self) -> &[TurnRecord] {
+        &self.turns
+    }
+
+    /// Where the game stands after the turns applied so far.
+    pub fn status(&self) -> GameStatus {
+        match self.turns.last() {
+            Some(turn) => self.check_game_status(&turn.results),
+            None => GameStatus::Ongoing,
+        }
+    }
+
+    /// Sends this game's events to `observer` from now on.
+    pub fn add_observer(&mut self, observer: Arc<dyn SolverObserver>) {
+        self.observers.push(observer);
+    }
+
+    /// A handle that stops guess scoring in progress when cancelled, for example
+    /// from a Ctrl-C handler. Every clone of this solver shares the same token.
+    pub fn cancel_token(&self) -> CancelToken {
+        self.cancel.clone()
+    }
+
+    // A copy for simulated play, whose events must not reach the observers
+    fn quiet_clone(&self) -> Self {
+        let mut solver = self.clone();
+        solver.observers.clear();
+        solver.keep_snapshots = false;
+        solver
+    }
+
+    // Makes `guess` the current guess as the solver's own suggestion
+    fn suggest(&mut self, guess: Option<String>) {
+        if let Some(guess) = &guess {
+            self.observers.iter().for_each(|observer| observer.suggestion_computed(guess));
+        }
+        self.current_guess = guess.clone();
+        self.suggested_guess = guess;
+    }
+
+    /// Picks the opening guess. The second-guess book for it fills in as each
+    /// turn-two pattern comes up, so only games that reach turn two pay for it.
+    pub fn start(&mut self) -> Option<&str> {
+        let opener = self.opening_guess();
+        self.second_guess_book =
+            opener.as_ref().map(|opener| SecondGuessBook { opener: opener.clone(), follow_ups: HashMap::new() });
+        self.suggest(opener);
+        self.current_guess()
+    }
+
+    /// Starts the game over with the same word lists and settings, keeping the
+    /// opener from [`WordleSolver::start`] and whatever of its second-guess book
+    /// has been filled in. A solver never started picks its opener as `start` would.
+    pub fn reset(&mut self) {
+        self.restart();
+        self.observers.iter().for_each(|observer| observer.turns_undone(0));
+        let opener = self.current_guess.take();
+        self.suggest(opener);
+    }
+
+    fn restart(&mut self) {
+        self.word_list = self.full_word_list.clone();
+        self.guess_list = self.full_guess_list.clone();
+        self.constraints = Constraints::new(self.word_length);
+        self.attempts = 0;
+        self.turns.clear();
+        self.snapshots.clear();
+        match &self.second_guess_book {
+            Some(book) => {
+                self.current_guess = Some(book.opener.clone());
+                self.suggested_guess = self.current_guess.clone();
+            }
+            None => {
+                self.start();
+            }
+        }
+    }
+
+    /// Rewinds the game to just after its first `turn_index` turns, as if the later
+    /// feedback had never been entered. Does nothing if there are no later turns.
+    pub fn truncate_to(&mut self, turn_index: usize) {
+        if turn_index >= self.turns.len() {
+            return;
+        }
+        self.turns.truncate(turn_index);
+        self.snapshots.truncate(turn_index + 1);
+        let snapshot = self.snapshots.pop().expect("every applied turn has a snapshot");
+        self.word_list = snapshot.word_list;
+        if self.hard_mode {
+            self.guess_list = snapshot.guess_list;
+        }
+        self.constraints = snapshot.constraints;
+        self.attempts = snapshot.attempts;
+
+        self.observers.iter().for_each(|observer| observer.turns_undone(turn_index));
+        // Offer the solver's own pick again rather than whatever was played
+        self.suggest(snapshot.suggested_guess.or(Some(snapshot.guess)));
+    }
+
+    /// Removes turn `turn`, counting from 1, and replays the turns after it as if it
+    /// had never been played, returning how many were replayed. A game won on its last
+    /// turn stays won. Leaves the game as it was when a later turn no longer fits
+    /// without it.
+    pub fn remove_turn(&mut self, turn: usize) -> Result<usize> {
+        if turn == 0 || turn > self.turns.len() {
+            return Err(Error::NoSuchTurn { turn, turns: self.turns.len() });
+        }
+        let later: Vec<(String, String)> =
+            self.turns[turn..].iter().map(|record| (record.guess.clone(), record.results.clone())).collect();
+        let mut trial = self.clone();
+        trial.truncate_to(turn - 1);
+        for (idx, (guess, results)) in later.iter().enumerate() {
+            let conflict = match trial.apply(guess, results) {
+                Ok(GameStatus::Ongoing) if trial.candidates().is_empty() => TurnConflict::NoWordFits,
+                Ok(GameStatus::Ongoing) => continue,
+                // Only a turn that isn't last can end the game too soon
+                Ok(GameStatus::Won) if idx + 1 == later.len() => continue,
+                Ok(_) => TurnConflict::GameOver,
+                Err(e) => TurnConflict::Refused(Box::new(e)),
+            };
+            return Err(Error::TurnKept { turn, guess: guess.clone(), results: results.clone(), conflict });
+        }
+        *self = trial;
+        Ok(later.len())
+    }
+
+    // Starts over and applies each guess and its feedback, picking a guess only after the last
+    fn replay(&mut self, turns: &[(String, String)]) {
+        self.restart();
+        for (guess, results) in turns {
+            self.process_results(guess, results);
+            self.attempts += 1;
+        }
+        if let Some((guess, results)) = turns.last() {
+            self.current_guess = self.booked_guess(Some(guess), results).or_else(|| self.make_guess());
+            self.suggested_guess = self.current_guess.clone();
+        }
+    }
+
+    /// Writes every turn so far to `path` as a transcript: one tab-separated line per
+    /// turn with the suggested and played guesses, the results, the candidates left
+    /// and when the results were entered.
+    pub fn export_transcript(&self, path: &str) -> Result<()> {
+        Transcript {
+            word_count: self.full_word_list.len(),
+            fingerprint: word_list_fingerprint(&self.full_word_list),
+            word_length: self.word_length,
+            strategy: self.strategy.label().to_string(),
+            turns: self
+                .turns
+                .iter()
+                .map(|turn| TranscriptTurn {
+                    suggested: turn.suggested.clone(),
+                    guess: turn.guess.clone(),
+                    results: turn.results.clone(),
+                    candidates_after: turn.candidates_after,
+                    applied_at: turn.applied_at,
+                })
+                .collect(),
+        }
+        .write(path)
+    }
+
+    /// Starts over and plays the guesses and results recorded in the transcript at
+    /// `path`, noting at each turn whether the solver still makes the suggestion the
+    /// transcript recorded. Call [`WordleSolver::start`] first so turn two is
+    /// suggested exactly as in a live game.
+    pub fn replay_transcript(&mut self, path: &str) -> Result<Vec<ReplayStep>> {
+        let transcript = Transcript::read(path)?;
+        if transcript.word_count != self.full_word_list.len()
+            || transcript.fingerprint != word_list_fingerprint(&self.full_word_list)
+        {
+            return Err(Error::SessionMismatch("it was recorded with a different word list".to_string()));
+        }
+        if transcript.word_length != self.word_length {
+            return Err(Error::SessionMismatch(format!("it was recorded with {}-letter words", transcript.word_length)));
+        }
+
+        self.reset();
+        let mut steps = Vec::new();
+        for turn in transcript.turns {
+            if !valid_word(&turn.guess, self.word_length) {
+                return Err(Error::InvalidSession { path: path.to_string(), reason: format!("bad guess '{}'", turn.guess) });
+            }
+            steps.push(ReplayStep {
+                guess: turn.guess.clone(),
+                recorded: turn.suggested,
+                suggested: self.suggested_guess.clone(),
+            });
+            self.choose_guess(&turn.guess);
+            if self.next_turn(&turn.results)? != GameStatus::Ongoing {
+                break;
+            }
+        }
+        Ok(steps)
+    }
+
+    /// Writes the game so far to `path`, along with what's needed to check that
+    /// it is later resumed against the same word list and rules.
+    pub fn save_session(&self, path: &str) -> Result<()> {
+        SavedSession {
+            word_count: self.full_word_list.len(),
+            fingerprint: word_list_fingerprint(&self.full_word_list),
+            word_length: self.word_length,
+            hard_mode: self.hard_mode,
+            max_attempts: self.max_attempts,
+            turns: self.turns.iter().map(|turn| (turn.guess.clone(), turn.results.clone())).collect(),
+            current_guess: self.current_guess.clone(),
+        }
+        .write(path)
+    }
+
+    /// Replaces the game in progress with the one saved at `path`. The session must
+    /// have been saved from the same word list, word length and rules.
+    pub fn load_session(&mut self, path: &str) -> Result<()> {
+        let session = SavedSession::read(path)?;
+        if session.word_count != self.full_word_list.len()
+            || session.fingerprint != word_list_fingerprint(&self.full_word_list)
+        {
+            return Err(Error::SessionMismatch("it was saved with a different word list".to_string()));
+        }
+        if session.word_length != self.word_length {
+            return Err(Error::SessionMismatch(format!("it was saved with {}-letter words", session.word_length)));
+        }
+        if session.hard_mode != self.hard_mode {
+            let mode = if session.hard_mode { "in hard mode" } else { "outside hard mode" };
+            return Err(Error::SessionMismatch(format!("it was saved {}", mode)));
+        }
+        if session.max_attempts != self.max_attempts {
+            return Err(Error::SessionMismatch(format!("it was saved with {} attempts", session.max_attempts)));
+        }
+        for (guess, results) in &session.turns {
+            if !valid_word(guess, self.word_length) || Feedback::parse(results, self.word_length).is_err() {
+                return Err(Error::InvalidSession {
+                    path: path.to_string(),
+                    reason: format!("bad turn '{} {}'", guess, results),
+                });
+            }
+        }
+
+        self.replay(&session.turns);
+        if let Some(guess) = session.current_guess.filter(|guess| valid_word(guess, self.word_length)) {
+            self.current_guess = Some(guess);
+        }
+        Ok(())
+    }
+
+    /// Replaces the current guess, e.g. with another member of [`WordleSolver::tie_set`].
+    pub fn choose_guess(&mut self, word: &str) {
+        self.current_guess = Some(word.to_string());
+    }
+
+    // The words the next guess is picked from: only the candidates when the probe
+    // policy rules out probes for this turn
+    fn guess_pool(&self) -> &[String] {
+        let probes_allowed = match self.probe_policy {
+            ProbePolicy::Always => true,
+            ProbePolicy::NotLast => self.attempts + 1 < self.max_attempts,
+            ProbePolicy::Never => false,
+        };
+        if probes_allowed {
+            self.guess_list.as_deref().unwrap_or(&self.word_list)
+        } else {
+            &self.word_list
+        }
+    }
+
+    /// The strategy in charge for the current number of candidates.
+    pub fn active_strategy(&self) -> Strategy {
+        let count = self.word_list.len();
+        match (self.phase_thresholds, self.exact_below) {
+            (Some(thresholds), _) if count < thresholds.guarantee_below => Strategy::Guarantee,
+            (_, Some(exact_below)) if count < exact_below => Strategy::Exact,
+            (Some(thresholds), _) if count < thresholds.expected_below => Strategy::Expected,
+            _ => self.strategy,
+        }
+    }
+
+    // The configured opener before any turn is played, otherwise the strategy's pick
+    fn opening_guess(&self) -> Option<String> {
+        self.opener.clone().filter(|_| self.turns.is_empty()).or_else(|| self.make_guess())
+    }
+
+    /// The best guess for the current candidates under the active strategy.
+    pub fn make_guess(&self) -> Option<String> {
+        if let Some(forced) = self.forced_guess() {
+            return Some(forced);
+        }
+        match self.active_strategy() {
+            Strategy::Entropy => self.tie_set().into_iter().next(),
+            strategy => strategy.best_guess(
+                self.guess_pool(),
+                &self.word_list,
+                &self.word_weights,
+                self.beam,
+                self.seed,
+                &self.cancel,
+            ),
+        }
+    }
+
+    /// The guess that needs no scoring: the answer itself when one candidate is left,
+    /// or the likelier of the two when two are, since guessing either can only win or
+    /// leave the other.
+    pub fn forced_guess(&self) -> Option<String> {
+        if self.word_list.len() > 2 {
+            return None;
+        }
+        self.candidate_probabilities().into_iter().next().map(|(word, _)| word)
+    }
+
+    /// Every guess scoring within `tie_epsilon` of the best, restricted to possible
+    /// answers when any of them are tied, in the order the tie policy prefers.
+    pub fn tie_set(&self) -> Vec<String> {
+        if self.forced_guess().is_some() || self.active_strategy() != Strategy::Entropy {
+            return self.make_guess().into_iter().collect();
+        }
+
+        let scores = self.scored_guesses();
+        let best_entropy = match scores.first() {
+            Some(score) => score.entropy,
+            None => return Vec::new(),
+        };
+        let mut ties: Vec<String> = scores
+            .into_iter()
+            .take_while(|score| best_entropy - score.entropy <= self.tie_epsilon)
+            .map(|score| score.word)
+            .collect();
+
+        let candidate_set: HashSet<&str> = self.word_list.iter().map(String::as_str).collect();
+        if ties.iter().any(|word| candidate_set.contains(word.as_str())) {
+            ties.retain(|word| candidate_set.contains(word.as_str()));
+        }
+        ties.sort();
+        if self.tie_policy == TiePolicy::Frequent {
+            ties.sort_by(|a, b| {
+                word_weight(&self.word_weights, b).partial_cmp(&word_weight(&self.word_weights, a)).unwrap()
+            });
+        }
+        ties
+    }
+
+    /// Every allowed guess scored against the candidates, best first.
+    ///
+    /// Stops early once the solver's [`CancelToken`] is cancelled, returning only the
+    /// guesses scored by then.
+    pub fn scored_guesses(&self) -> Vec<GuessScore> {
+        let mut scores: Vec<GuessScore> = self.guess_pool()
+            .par_iter()
+            .filter(|_| !self.cancel.is_cancelled())
+            .map(|word| score_guess(word, &self.word_list, &self.word_weights))
+            .collect();
+
+        // Highest entropy first, smaller worst case breaking ties
+        scores.sort_by(|a, b| {
+            b.entropy.partial_cmp(&a.entropy).unwrap().then_with(|| a.worst_case.cmp(&b.worst_case))
+        });
+        scores
+    }
+
+    /// Estimated guesses still needed, including the current one.
+    pub fn expected_remaining_guesses(&self) -> f64 {
+        expected_guesses(
+            &self.word_list,
+            self.guess_list.as_deref(),
+            &self.word_weights,
+            self.active_strategy(),
+            self.beam,
+            self.seed,
+            EXPECTATION_DEPTH,
+            &self.cancel,
+        )
+    }
+
+    /// Chance the solver finds the answer within `max_attempts` if it keeps following its
+    /// strategy from the current guess, weighing each candidate by its probability.
+    pub fn win_probability(&self) -> f64 {
+        let guess = match &self.current_guess {
+            Some(guess) if !self.word_list.is_empty() => guess,
+            _ => return 0.0,
+        };
+        let won_weight: f64 = self.word_list
+            .par_iter()
+            .filter(|secret| self.play_out(secret, guess).1)
+            .map(|secret| word_weight(&self.word_weights, secret))
+            .sum();
+        won_weight / total_weight(&self.word_list, &self.word_weights)
+    }
+
+    /// Groups of at least [`MIN_FAMILY_SIZE`] candidates differing in one position, largest first.
+    pub fn candidate_families(&self) -> Vec<CandidateFamily> {
+        let mut families: Vec<CandidateFamily> = (0..self.word_length)
+            .flat_map(|idx| {
+                let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+                for word in &self.word_list {
+                    let mut pattern = word.clone();
+                    pattern.replace_range(idx..idx + 1, "_");
+                    groups.entry(pattern).or_default().push(word.clone());
+                }
+                groups.into_iter()
+            })
+            .filter(|(_, words)| words.len() >= MIN_FAMILY_SIZE)
+            .map(|(pattern, words)| CandidateFamily { pattern, words })
+            .collect();
+
+        families.sort_by(|a, b| b.words.len().cmp(&a.words.len()).then_with(|| a.pattern.cmp(&b.pattern)));
+        families
+    }
+
+    /// The allowed guess that best splits a family apart, whether or not it could be the answer.
+    pub fn family_probe(&self, family: &CandidateFamily) -> Option<String> {
+        let probe_pool = match &self.guess_list {
+            Some(guess_list) => guess_list,
+            None if self.hard_mode => &self.word_list,
+            None => &self.full_word_list,
+        };
+        best_entropy_guess(probe_pool, &family.words, &self.word_weights)
+    }
+
+    /// Each remaining candidate with its chance of being the answer, likeliest first.
+    pub fn candidate_probabilities(&self) -> Vec<(String, f64)> {
+        // Each remaining candidate is as likely as its share of the total weight
+        let total_weight = total_weight(&self.word_list, &self.word_weights);
+        let mut probabilities: Vec<(String, f64)> = self.word_list
+            .iter()
+            .map(|word| (word.clone(), word_weight(&self.word_weights, word) / total_weight))
+            .collect();
+
+        // Most likely answers first, alphabetical among equals
+        probabilities.sort_by(|(word_a, prob_a), (word_b, prob_b)| {
+            prob_b.partial_cmp(prob_a).unwrap().then_with(|| word_a.cmp(word_b))
+        });
+        probabilities
+    }
+
+    fn process_results(&mut self, guess: &str, results: &str) {
+        let counts = pattern_counts(guess, &self.word_list, &self.word_weights);
+        let weight_before = total_weight(&self.word_list, &self.word_weights);
+        let entropy_before = distribution_entropy(&self.word_list, &self.word_weights);
+        if self.keep_snapshots {
+            self.snapshots.push(Snapshot {
+                word_list: self.word_list.clone(),
+                guess_list: if self.hard_mode { self.guess_list.clone() } else { None },
+                constraints: self.constraints.clone(),
+                attempts: self.attempts,
+                guess: guess.to_string(),
+                suggested_guess: self.suggested_guess.clone(),
+            });
+        }
+
+        // Every path here has already checked the guess and results against the word length
+        self.constraints.add_feedback(guess, results).expect("a checked guess and results");
+        // Filter in place so each turn reuses the lists' storage
+        let constraints = &self.constraints;
+        self.word_list.retain(|word| constraints.allows(word));
+        if self.hard_mode {
+            if let Some(guess_list) = &mut self.guess_list {
+                guess_list.retain(|word| constraints.allows_hard_mode_guess(word));
+            }
+        }
+
+        self.turns.push(TurnRecord {
+            guess: guess.to_string(),
+            suggested: self.suggested_guess.take(),
+            results: results.to_string(),
+            applied_at: self.clock.now(),
+            candidates_after: self.word_list.len(),
+            entropy_before,
+            entropy_after: distribution_entropy(&self.word_list, &self.word_weights),
+            expected_information: entropy_of_counts(&counts, weight_before),
+            actual_information: counts.get(results).map(|&(_, weight)| (weight_before / weight).log2()),
+        });
+    }
+
+    fn check_game_status(&self, results: &str) -> GameStatus {
+        if results.chars().all(|c| c == 'G') {
+            GameStatus::Won
+        } else if self.attempts >= self.max_attempts {
+            GameStatus::Lost
+        } else {
+            GameStatus::Ongoing
+        }
+    }
+
+    /// Applies the feedback for the current guess, a string of 'G', 'Y' and 'B',
+    /// and picks the next guess if the game goes on.
+    pub fn next_turn(&mut self, results: &str) -> Result<GameStatus> {
+        let code = parse_results_code(results, self.word_length)?;
+        // A word picked with choose_guess hasn't been checked yet
+        if let Some(guess) = &self.current_guess {
+            if !valid_word(guess, self.word_length) {
+                return Err(Error::InvalidWord { word: guess.clone(), length: self.word_length });
+            }
+        }
+        Ok(self.take_turn(code))
+    }
+
+    // Plays a checked guess and its feedback, refusing guesses hard mode rules out. A board
+    // played alongside others leaves `pick_next` unset, since the boards pick together
+    pub(crate) fn play_turn(&mut self, guess: &str, code: PatternCode, pick_next: bool) -> Result<GameStatus> {
+        if self.hard_mode {
+            if let Some(rule) = self.constraints.hard_mode_violation(guess) {
+                return Err(Error::HardMode { word: guess.to_string(), rule });
+            }
+        }
+        self.choose_guess(guess);
+        Ok(self.apply_turn(code, pick_next))
+    }
+
+    // Applies checked feedback for the current guess and picks the next one
+    fn take_turn(&mut self, code: PatternCode) -> GameStatus {
+        self.apply_turn(code, true)
+    }
+
+    fn apply_turn(&mut self, code: PatternCode, pick_next: bool) -> GameStatus {
+        let results = &code.results(self.word_length);
+        // Temporarily take the current_guess out of self to avoid mutable-immutable borrow conflict
+        let current_guess = self.current_guess.take();
+        if let Some(ref guess) = current_guess {
+            self.process_results(guess, results);
+            let turn = self.turns.last().unwrap();
+            self.observers.iter().for_each(|observer| observer.turn_applied(turn));
+        }
+        self.attempts += 1;
+        let game_status = self.check_game_status(results);
+
+        if game_status == GameStatus::Ongoing && pick_next {
+            let next_guess = self
+                .booked_guess(current_guess.as_deref(), results)
+                .or_else(|| self.make_guess());
+            self.suggest(next_guess);
+        } else if game_status != GameStatus::Ongoing {
+            self.current_guess = None;
+            self.observers.iter().for_each(|observer| observer.game_over(game_status, self.attempts));
+        }
+
+        // Put the current_guess back in case it was taken out
+        if self.current_guess.is_none() {
+            self.current_guess = current_guess;
+        }
+
+        game_status
+    }
+
+    // Works out the turn-two guess for every pattern `opener` can produce up front, so
+    // that batch play shares one table instead of scoring turn two per game. Once cancelled
+    // the book is left short; missing patterns are scored when they come up
+    fn prepare_second_guess_book(&mut self, opener: &str) {
+        let follow_ups = partition_by_pattern(opener, &self.word_list)
+            .into_keys()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .filter(|_| !self.cancel.is_cancelled())
+            .filter_map(|pattern| {
+                let mut solver = self.quiet_clone();
+                solver.process_results(opener, &pattern);
+                // A guess found after cancelling may be a partial search's pick
+                let guess = solver.make_guess().filter(|_| !self.cancel.is_cancelled())?;
+                Some((pattern, guess))
+            })
+            .collect();
+
+        self.second_guess_book = Some(SecondGuessBook { opener: opener.to_string(), follow_ups });
+    }
+
+    // The turn-two guess after the opener got `results`, scored the first time the
+    // pattern comes up and looked up after that, so restarted games reuse it
+    fn booked_guess(&mut self, guess: Option<&str>, results: &str) -> Option<String> {
+        let book = self.second_guess_book.as_ref()?;
+        if self.attempts != 1 || guess != Some(book.opener.as_str()) {
+            return None;
+        }
+        if let Some(follow_up) = book.follow_ups.get(results) {
+            return Some(follow_up.clone());
+        }
+        let follow_up = self.make_guess()?;
+        // A guess found after cancelling may be a partial search's pick, so it isn't kept
+        if !self.cancel.is_cancelled() {
+            if let Some(book) = &mut self.second_guess_book {
+                book.follow_ups.insert(results.to_string(), follow_up.clone());
+            }
+        }
+        Some(follow_up)
+    }
+
+    /// Plays the strategy against every secret from the current state without any
+    /// interaction, sharing the opener and second-guess book across games.
+    ///
+    /// Stops early once the solver's [`CancelToken`] is cancelled, returning only the
+    /// games finished before then.
+    pub fn play_games(&mut self, secrets: &[String]) -> Vec<GameRecord> {
+        let opener = match self.opening_guess() {
+            Some(opener) => opener,
+            None => return Vec::new(),
+        };
+        self.prepare_second_guess_book(&opener);
+
+        let solver = &*self;
+        secrets
+            .par_iter()
+            .filter(|_| !solver.cancel.is_cancelled())
+            .filter_map(|secret| {
+                let (guesses, won) = solver.play_out(secret, &opener);
+                // A game still running when cancelled was played with cut-short searches
+                (!solver.cancel.is_cancelled()).then(|| GameRecord { secret: secret.clone(), guesses, won })
+            })
+            .collect()
+    }
+
+    /// Plays a copy of this solver against a known secret, starting from `opener`.
+    /// Returns every guess made and whether the secret was found within `max_attempts`.
+    pub fn play_out(&self, secret: &str, opener: &str) -> (Vec<String>, bool) {
+        let mut solver = self.quiet_clone();
+        solver.current_guess = Some(opener.to_string());
+        let mut guesses = Vec::new();
+
+        loop {
+            let guess = solver.current_guess.clone().unwrap();
+            let code = simulate_results_pattern(&guess, secret);
+            guesses.push(guess);
+            match solver.take_turn(code) {
+                GameStatus::Won => return (guesses, true),
+                GameStatus::Lost => return (guesses, false),
+                GameStatus::Ongoing => {}
+            }
+        }
+    }
+}
+
+/// Plays `strategy` to completion against each of `secrets`, with the possible answers
+/// from `source` and every other setting at its default. For other settings, build a
+/// solver and call [`WordleSolver::play_games`] instead.
+pub fn solve_batch(source: impl WordSource + 'static, strategy: Strategy, secrets: &[String]) -> Result<Vec<GameRecord>> {
+    let mut solver = WordleSolver::builder_from(source).strategy(strategy).build()?;
+    let secrets = secrets
+        .iter()
+        .map(|secret| Word::parse(secret, solver.word_length()).map(Word::into_string))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(solver.play_games(&secrets))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::words;
+
+    fn solver() -> WordleSolver {
+        WordleSolver::builder_from(words(&["break", "bread", "cream", "dream", "crane", "irate", "great", "treat", "slimy", "stare"]))
+            .build()
+            .unwrap()
+    }
+
+    fn booked_patterns(solver: &WordleSolver) -> usize {
+        solver.second_guess_book.as_ref().map_or(0, |book| book.follow_ups.len())
+    }
+
+    #[test]
+    fn second_guess_book_fills_in_as_patterns_come_up() {
+        let mut solver = solver();
+        let opener = solver.start().unwrap().to_string();
+        assert_eq!(booked_patterns(&solver), 0);
+
+        let results = simulate_results(&opener, "break");
+        solver.next_turn(&results).unwrap();
+        assert_eq!(booked_patterns(&solver), 1);
+        let second = solver.current_guess().map(str::to_string);
+
+        // A restarted game reaching the same pattern looks the guess up
+        solver.reset();
+        solver.next_turn(&results).unwrap();
+        assert_eq!(booked_patterns(&solver), 1);
+        assert_eq!(solver.current_guess().map(str::to_string), second);
+    }
+
+    #[test]
+    fn common_bias_ranks_more_frequent_words_first() {
+        // Words alike but for one letter, so only their frequency tells them apart
+        let ranked = |solver: &WordleSolver| solver.candidate_probabilities().into_iter().map(|(word, _)| word).collect::<Vec<_>>();
+
+        let unbiased = WordleSolver::builder_from(words(&["sight", "tight", "light"])).build().unwrap();
+        assert_eq!(ranked(&unbiased), ["light", "sight", "tight"]);
+
+        let biased = WordleSolver::builder_from(words(&["sight", "tight", "light"])).common_bias(1.0).build().unwrap();
+        assert_eq!(ranked(&biased), ["light", "tight", "sight"]);
+
+        // With two left, the guess is the more frequent of them
+        let pair = WordleSolver::builder_from(words(&["sight", "light"])).common_bias(1.0).build().unwrap();
+        assert_eq!(pair.forced_guess().as_deref(), Some("light"));
+    }
+
+    #[test]
+    fn batch_play_books_every_opener_pattern() {
+        let mut solver = solver();
+        let opener = solver.start().unwrap().to_string();
+        let secrets = solver.candidates().to_vec();
+        solver.play_games(&secrets);
+        assert_eq!(booked_patterns(&solver), partition_by_pattern(&opener, &secrets).len());
+    }
+
+    #[test]
+    fn families_group_candidates_differing_in_one_letter() {
+        let solver = WordleSolver::builder_from(words(&["fight", "light", "might", "night", "fifty", "sight", "sided", "sides", "siren"]))
+            .build()
+            .unwrap();
+        let families = solver.candidate_families();
+        assert_eq!(families.len(), 1);
+        assert_eq!(families[0].pattern, "_ight");
+        assert_eq!(families[0].words, ["fight", "light", "might", "night", "sight"]);
+        // Pairs like sided/sides fall short of a family, and the probe splits this one at least three ways
+        let probe = solver.family_probe(&families[0]).unwrap();
+        let pattern_count = partition_by_pattern(&probe, &families[0].words).len();
+        assert!(pattern_count > 2, "{} splits {} ways", probe, pattern_count);
+    }
+
+    #[test]
+    fn ledger_records_the_information_each_turn_gave() {
+        let mut solver = solver();
+        solver.choose_guess("crane");
+        solver.next_turn(&simulate_results("crane", "break")).unwrap();
+        let turn = &solver.turns()[0];
+        // Ten equally likely words down to five is one bit, out of log2(10) before
+        assert_eq!(turn.candidates_after, 5);
+        assert!((turn.entropy_before - 10f64.log2()).abs() < 1e-9);
+        assert!((turn.entropy_after - 5f64.log2()).abs() < 1e-9);
+        assert!((turn.actual_information.unwrap() - 1.0).abs() < 1e-9);
+        assert!(turn.expected_information > 0.0 && turn.expected_information <= 10f64.log2());
+    }
+
+    #[test]
+    fn frequent_tie_policy_prefers_heavier_words() {
+        let priors: WordWeights = [("sight".to_string(), 3.0)].into_iter().collect();
+        let build = |policy: TiePolicy| {
+            WordleSolver::builder_from(words(&["fight", "light", "sight"]))
+                .priors(priors.clone())
+                .tie_epsilon(1.0)
+                .tie_policy(policy)
+                .build()
+                .unwrap()
+        };
+        // Every guess counts as tied this far from the best
+        assert_eq!(build(TiePolicy::First).tie_set(), ["fight", "light", "sight"]);
+        assert_eq!(build(TiePolicy::Frequent).tie_set(), ["sight", "fight", "light"]);
+    }
+
+    #[test]
+    fn candidate_probabilities_follow_the_priors() {
+        let priors: WordWeights = [("break".to_string(), 3.0)].into_iter().collect();
+        let solver = WordleSolver::builder_from(words(&["bread", "break", "cream"])).priors(priors).build().unwrap();
+        let probabilities = solver.candidate_probabilities();
+        let ranked: Vec<&str> = probabilities.iter().map(|(word, _)| word.as_str()).collect();
+        assert_eq!(ranked, ["break", "bread", "cream"]);
+        assert!((probabilities[0].1 - 0.6).abs() < 1e-9);
+        assert!((probabilities.iter().map(|(_, probability)| probability).sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn win_probability_counts_the_secrets_found_in_time() {
+        let mut solver = solver();
+        solver.start();
+        // Ten words and six guesses leave the solver nothing it can't find
+        assert_eq!(solver.win_probability(), 1.0);
+
+        let mut tight = WordleSolver::builder_from(words(&["fight", "light", "might", "night", "sight", "tight"]))
+            .max_attempts(2)
+            .build()
+            .unwrap();
+        tight.start();
+        // Two guesses at a six-word family can find at most two of them
+        assert!(tight.win_probability() <= 2.0 / 6.0 + 1e-9);
+    }
+
+    #[test]
+    fn expected_guesses_grow_with_the_candidates() {
+        let one = WordleSolver::builder_from(words(&["break"])).build().unwrap();
+        assert_eq!(one.expected_remaining_guesses(), 1.0);
+        // Two words: the first guess wins half the time, the second always
+        let two = WordleSolver::builder_from(words(&["bread", "break"])).build().unwrap();
+        assert!((two.expected_remaining_guesses() - 1.5).abs() < 1e-9);
+        let ten = solver().expected_remaining_guesses();
+        assert!(ten > 1.5 && ten < 4.0, "{}", ten);
+        // The estimate plays on with whichever strategy is in charge
+        let mut handed_over = solver();
+        handed_over.phase_thresholds = Some(PhaseThresholds { expected_below: 20, guarantee_below: 20 });
+        let guarantee = expected_guesses(
+            &handed_over.word_list,
+            None,
+            &handed_over.word_weights,
+            Strategy::Guarantee,
+            handed_over.beam,
+            handed_over.seed,
+            EXPECTATION_DEPTH,
+            &handed_over.cancel,
+        );
+        assert_eq!(handed_over.expected_remaining_guesses(), guarantee);
+    }
+
+    #[test]
+    fn strategy_hands_over_as_the_candidates_dwindle() {
+        let mut solver = solver();
+        solver.phase_thresholds = Some(PhaseThresholds { expected_below: 20, guarantee_below: 5 });
+        assert_eq!(solver.active_strategy(), Strategy::Expected);
+        solver.phase_thresholds = Some(PhaseThresholds { expected_below: 8, guarantee_below: 5 });
+        assert_eq!(solver.active_strategy(), Strategy::Entropy);
+        solver.choose_guess("crane");
+        solver.next_turn(&simulate_results("crane", "slimy")).unwrap();
+        assert!(solver.candidates().len() < 5);
+        assert_eq!(solver.active_strategy(), Strategy::Guarantee);
+    }
+
+    #[test]
+    fn other_word_lengths_solve_the_same_way() {
+        let mut solver = WordleSolver::builder_from(words(&["bark", "bead", "cram", "slim", "crane"])).word_length(4).build().unwrap();
+        // Words of other lengths are left out of the game
+        assert_eq!(solver.candidates(), ["bark", "bead", "cram", "slim"]);
+        solver.start();
+        assert!(matches!(solver.next_turn("BBBBB"), Err(Error::InvalidResults { length: 4, .. })));
+        let records = solver.play_games(&words(&["bark", "slim"]));
+        assert!(records.iter().all(|record| record.won));
+    }
+
+    #[test]
+    fn builder_refuses_unusable_settings() {
+        let build = |builder: WordleSolverBuilder| builder.build().err();
+        let base = || WordleSolver::builder_from(words(&["break", "bread"]));
+        for error in [
+            build(base().word_length(0)),
+            build(base().word_length(PatternCode::MAX_LETTERS + 1)),
+            build(base().max_attempts(0)),
+            build(base().strategy(Strategy::Beam).beam(BeamSettings { width: 0, depth: 2 })),
+            build(base().phase_thresholds(PhaseThresholds { expected_below: 5, guarantee_below: 10 })),
+            build(base().tie_epsilon(-1.0)),
+            build(base().common_bias(0.0)),
+            build(base().priors([("break".to_string(), f64::NAN)].into_iter().collect())),
+        ] {
+            assert!(matches!(error, Some(Error::InvalidSettings(_))), "{:?}", error);
+        }
+        assert!(matches!(build(base().opener("br3ak")), Some(Error::InvalidWord { .. })));
+        assert!(matches!(build(WordleSolver::builder_from(Vec::<String>::new())), Some(Error::NoWords { .. })));
+    }
+
+    #[test]
+    fn reset_starts_over_with_the_same_opener() {
+        let build = || WordleSolver::builder_from(words(&["break", "bread", "cream", "slimy"])).opener("slimy").build().unwrap();
+        let mut solver = build();
+        assert_eq!(solver.start(), Some("slimy"));
+        solver.next_turn("BBBBB").unwrap();
+        solver.reset();
+        assert!(solver.turns().is_empty());
+        assert_eq!(solver.attempts(), 0);
+        assert_eq!(solver.candidates(), ["break", "bread", "cream", "slimy"]);
+        assert_eq!(solver.current_guess(), Some("slimy"));
+
+        // A solver never started gets the opener and book that start would give it
+        let mut fresh = build();
+        fresh.reset();
+        assert_eq!(fresh.current_guess(), Some("slimy"));
+        assert_eq!(fresh.second_guess_book.map(|book| book.opener).as_deref(), Some("slimy"));
+    }
+
+    #[test]
+    fn observers_hear_each_event() {
+        use std::sync::Mutex;
+
+        #[derive(Default)]
+        struct Recorder(Mutex<Vec<String>>);
+
+        impl SolverObserver for Recorder {
+            fn suggestion_computed(&self, guess: &str) {
+                self.0.lock().unwrap().push(format!("suggest {}", guess));
+            }
+            fn turn_applied(&self, turn: &TurnRecord) {
+                self.0.lock().unwrap().push(format!("turn {}={}", turn.guess, turn.results));
+            }
+            fn turns_undone(&self, turns_kept: usize) {
+                self.0.lock().unwrap().push(format!("undo {}", turns_kept));
+            }
+            fn game_over(&self, status: GameStatus, attempts: usize) {
+                self.0.lock().unwrap().push(format!("over {:?} {}", status, attempts));
+            }
+        }
+
+        let recorder = Arc::new(Recorder::default());
+        let mut solver = WordleSolver::builder_from(words(&["break", "bread", "slimy"])).opener("slimy").build().unwrap();
+        solver.add_observer(recorder.clone());
+        solver.start();
+        solver.next_turn("BBBBB").unwrap();
+        solver.truncate_to(0);
+        solver.next_turn("BBBBB").unwrap();
+        solver.choose_guess("break");
+        solver.next_turn("GGGGG").unwrap();
+        // Simulated games never reach the observers
+        solver.win_probability();
+
+        let events = recorder.0.lock().unwrap().clone();
+        assert_eq!(
+            events,
+            [
+                "suggest slimy",
+                "turn slimy=BBBBB",
+                "suggest bread",
+                "undo 0",
+                "suggest slimy",
+                "turn slimy=BBBBB",
+                "suggest bread",
+                "turn break=GGGGG",
+                "over Won 2",
+            ]
+        );
+    }
+
+    #[test]
+    fn undo_restores_the_turn_from_its_snapshot() {
+        let words = solver().candidates().to_vec();
+        let mut solver = WordleSolver::builder_from(words.clone()).hard_mode(true).build().unwrap();
+        let opener = solver.start().unwrap().to_string();
+        let guesses_before = solver.guess_list.clone();
+        solver.choose_guess("slimy");
+        solver.next_turn(&simulate_results("slimy", "break")).unwrap();
+        assert_eq!(solver.snapshots.len(), 1);
+
+        solver.truncate_to(0);
+        // The solver's own pick comes back rather than the word played instead
+        assert_eq!(solver.current_guess(), Some(opener.as_str()));
+        assert_eq!(solver.candidates(), words);
+        assert_eq!(solver.guess_list, guesses_before);
+        assert!(solver.snapshots.is_empty());
+        // Simulated games never undo, so they keep no snapshots
+        assert!(!solver.quiet_clone().keep_snapshots);
+    }
+
+    #[test]
+    fn last_turn_eliminations_carry_their_patterns() {
+        let mut solver = solver();
+        assert!(solver.last_eliminated().is_empty());
+        solver.choose_guess("crane");
+        solver.next_turn(&simulate_results("crane", "break")).unwrap();
+        let eliminated = solver.last_eliminated();
+        let words: Vec<&str> = eliminated.iter().map(|elimination| elimination.word.as_str()).collect();
+        assert_eq!(words, ["cream", "crane", "irate", "slimy", "stare"]);
+        assert_eq!(solver.candidates(), ["break", "bread", "dream", "great", "treat"]);
+        for elimination in &eliminated {
+            assert_eq!(elimination.pattern, simulate_results("crane", &elimination.word));
+        }
+    }
+
+    #[test]
+    fn turns_are_stamped_by_the_clock() {
+        let at = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_760_630_400);
+        let mut solver = WordleSolver::builder_from(solver().candidates().to_vec())
+            .clock(crate::clock::FixedClock(at))
+            .build()
+            .unwrap();
+        let opener = solver.start().unwrap().to_string();
+        solver.next_turn(&simulate_results(&opener, "break")).unwrap();
+        assert_eq!(solver.turns()[0].applied_at, at);
+    }
+
+    #[test]
+    fn batch_summary_counts_wins_and_failures() {
+        let records = [
+            GameRecord { secret: "break".to_string(), guesses: words(&["crane", "break"]), won: true },
+            GameRecord { secret: "bread".to_string(), guesses: words(&["bread"]), won: true },
+            GameRecord { secret: "slimy".to_string(), guesses: words(&["crane", "bread", "break"]), won: false },
+        ];
+        let summary = BatchSummary::from_records(&records, 3);
+        assert_eq!(summary.games, 3);
+        assert!((summary.average - 2.0).abs() < 1e-9);
+        assert_eq!(summary.distribution, [1, 1, 0]);
+        assert_eq!(summary.failures, ["slimy"]);
+
+        // Every secret is solved headlessly, in at most the allowed guesses
+        let records = solve_batch(words(&["break", "bread", "cream", "slimy"]), Strategy::Entropy, &words(&["Slimy", "break"])).unwrap();
+        assert!(records.iter().all(|record| record.won && record.guess_count() <= 6));
+        assert!(matches!(solve_batch(words(&["break"]), Strategy::Fast, &words(&["zz"])), Err(Error::InvalidWord { .. })));
+    }
+
+    #[test]
+    fn one_or_two_candidates_force_the_guess() {
+        let one = WordleSolver::builder_from(words(&["break"])).build().unwrap();
+        assert_eq!(one.forced_guess().as_deref(), Some("break"));
+        assert_eq!(one.make_guess().as_deref(), Some("break"));
+        assert_eq!(solver().forced_guess(), None);
+        // Tied pairs go alphabetically
+        let pair = WordleSolver::builder_from(words(&["bread", "break"])).build().unwrap();
+        assert_eq!(pair.tie_set(), ["bread"]);
+    }
+
+    #[test]
+    fn probe_policy_limits_suggestions_to_candidates() {
+        let candidates = words(&["fight", "light", "might", "night", "sight", "tight"]);
+        let build = |policy: ProbePolicy, max_attempts: usize| {
+            WordleSolver::builder_from(candidates.clone())
+                .guesses_from(words(&["flmns"]))
+                .probe_policy(policy)
+                .max_attempts(max_attempts)
+                .build()
+                .unwrap()
+        };
+        // The probe tells five of the six apart, which no candidate can
+        assert_eq!(build(ProbePolicy::Always, 6).make_guess().as_deref(), Some("flmns"));
+        assert!(candidates.contains(&build(ProbePolicy::Never, 6).make_guess().unwrap()));
+        assert_eq!(build(ProbePolicy::NotLast, 6).make_guess().as_deref(), Some("flmns"));
+        assert!(candidates.contains(&build(ProbePolicy::NotLast, 1).make_guess().unwrap()));
+    }
+
+    // A solver over the test words fed `guesses` against "break"
+    fn played(guesses: &[&str]) -> WordleSolver {
+        let mut solver = solver();
+        solver.start();
+        for guess in guesses {
+            solver.apply(guess, &simulate_results(guess, "break")).unwrap();
+        }
+        solver
+    }
+
+    fn guesses(solver: &WordleSolver) -> Vec<&str> {
+        solver.turns().iter().map(|turn| turn.guess.as_str()).collect()
+    }
+
+    #[test]
+    fn removing_a_middle_turn_replays_the_rest_without_it() {
+        let mut solver = played(&["slimy", "stare", "spoon"]);
+        assert_eq!(solver.remove_turn(2).unwrap(), 1);
+        let fresh = played(&["slimy", "spoon"]);
+        assert_eq!(guesses(&solver), ["slimy", "spoon"]);
+        assert_eq!(solver.candidates(), fresh.candidates());
+        assert_eq!(solver.attempts(), 2);
+    }
+
+    #[test]
+    fn removing_a_turn_from_a_won_game_keeps_the_win() {
+        let mut solver = played(&["slimy", "stare", "break"]);
+        assert_eq!(solver.status(), GameStatus::Won);
+        assert_eq!(solver.remove_turn(2).unwrap(), 1);
+        assert_eq!(guesses(&solver), ["slimy", "break"]);
+        assert_eq!(solver.status(), GameStatus::Won);
+        assert_eq!(solver.attempts(), 2);
+    }
+
+    #[test]
+    fn removing_the_last_turn_replays_nothing() {
+        let mut solver = played(&["slimy", "stare", "spoon"]);
+        assert_eq!(solver.remove_turn(3).unwrap(), 0);
+        assert_eq!(guesses(&solver), ["slimy", "stare"]);
+        assert_eq!(solver.candidates(), played(&["slimy", "stare"]).candidates());
+    }
+
+    #[test]
+    fn removing_a_turn_out_of_range_changes_nothing() {
+        let mut solver = played(&["slimy", "stare"]);
+        let candidates = solver.candidates().to_vec();
+        for turn in [0, 3] {
+            assert!(matches!(solver.remove_turn(turn), Err(Error::NoSuchTurn { turns: 2, .. })), "turn {}", turn);
+        }
+        assert_eq!(guesses(&solver), ["slimy", "stare"]);
+        assert_eq!(solver.candidates(), candidates);
+    }
+
+    #[test]
+    fn truncating_rewinds_to_the_earlier_turns() {
+        let mut solver = played(&["slimy", "stare", "spoon"]);
+        solver.truncate_to(1);
+        assert_eq!(guesses(&solver), ["slimy"]);
+        assert_eq!(solver.candidates(), played(&["slimy"]).candidates());
+        assert_eq!(solver.attempts(), 1);
+
+        // Keeping every turn, or more than were played, leaves the game alone
+        solver.truncate_to(1);
+        solver.truncate_to(5);
+        assert_eq!(guesses(&solver), ["slimy"]);
+    }
+}
@@ -0,0 +1,355 @@
+//! A player's record across games, kept in a small file between runs.
+//!
+//! The file is plain text, one `key value` pair per line after a header:
+//!
+//! ```text
+//! wordle-solver stats
+//! played 12
+//! won 10
+//! distribution 0 2 4 3 1 0
+//! current-streak 3
+//! max-streak 5
+//! last-day 20377
+//! hints 4
+//! best-time-ms 48210
+//! missed vivid mamma
+//! ```
+//!
+//! Finished games are kept beside it in a game log, one game per line giving the
+//! day number, the secret and the guesses:
+//!
+//! ```text
+//! wordle-solver games
+//! 20377 break irate crane break
+//! ```
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use crate::error::{Error, Result};
+
+const HEADER: &str = "wordle-solver stats";
+const GAMES_HEADER: &str = "wordle-solver games";
+
+/// Games played and won, how many guesses the wins took, and the win streaks.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlayerStats {
+    pub played: usize,
+    pub won: usize,
+    /// Wins by number of guesses: the first entry counts wins in one guess
+    pub distribution: Vec<usize>,
+    /// Wins on consecutive days, up to the last game played
+    pub current_streak: usize,
+    pub max_streak: usize,
+    /// Day number of the last game played
+    pub last_day: Option<i64>,
+    /// Hints taken across every counted game
+    pub hints: usize,
+    /// Fastest timed win
+    pub best_time: Option<Duration>,
+    /// Secrets of lost games not yet solved in practice, oldest first
+    pub missed: Vec<String>,
+}
+
+impl PlayerStats {
+    /// Reads the stats at `path`, or empty stats if nothing has been saved there yet.
+    pub fn load(path: &str) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => return Err(Error::Io { path: path.to_string(), source }),
+        };
+        let invalid = |reason: String| Error::InvalidStats { path: path.to_string(), reason };
+
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+        if lines.next() != Some(HEADER) {
+            return Err(invalid(format!("missing '{}' header", HEADER)));
+        }
+        let mut stats = Self::default();
+        for line in lines {
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            let number = |value: &str| value.parse::<usize>().map_err(|_| invalid(format!("bad {} '{}'", key, value)));
+            match key {
+                "played" => stats.played = number(value)?,
+                "won" => stats.won = number(value)?,
+                "distribution" => stats.distribution = value.split_whitespace().map(number).collect::<Result<_>>()?,
+                "current-streak" => stats.current_streak = number(value)?,
+                "max-streak" => stats.max_streak = number(value)?,
+                "last-day" => {
+                    stats.last_day = Some(value.parse().map_err(|_| invalid(format!("bad last-day '{}'", value)))?);
+                }
+                "hints" => stats.hints = number(value)?,
+                "missed" => stats.missed = value.split_whitespace().map(str::to_string).collect(),
+                "best-time-ms" => stats.best_time = Some(Duration::from_millis(number(value)? as u64)),
+                _ => return Err(invalid(format!("unknown key '{}'", key))),
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Writes the stats to `path`, creating its directory if needed.
+    pub fn save(&self, path: &str) -> Result<()> {
+        let io_error = |source| Error::Io { path: path.to_string(), source };
+        if let Some(dir) = PathBuf::from(path).parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let mut text = format!("{}\n", HEADER);
+        text.push_str(&format!("played {}\nwon {}\n", self.played, self.won));
+        let distribution: Vec<String> = self.distribution.iter().map(usize::to_string).collect();
+        text.push_str(&format!("distribution {}\n", distribution.join(" ")));
+        text.push_str(&format!("current-streak {}\nmax-streak {}\n", self.current_streak, self.max_streak));
+        if let Some(day) = self.last_day {
+            text.push_str(&format!("last-day {}\n", day));
+        }
+        text.push_str(&format!("hints {}\n", self.hints));
+        if let Some(time) = self.best_time {
+            text.push_str(&format!("best-time-ms {}\n", time.as_millis()));
+        }
+        if !self.missed.is_empty() {
+            text.push_str(&format!("missed {}\n", self.missed.join(" ")));
+        }
+        fs::write(path, text).map_err(io_error)
+    }
+
+    /// Counts a game against `secret` played on day number `day`, won in `guesses`
+    /// guesses or lost (`None`). A win the day after the last game extends the streak,
+    /// another win the same day keeps it, and a day without a game breaks it. A game
+    /// for an earlier day is counted but leaves the streak alone. A lost secret is
+    /// remembered for practice.
+    pub fn record(&mut self, day: i64, secret: &str, guesses: Option<usize>) {
+        let previous = self.last_day;
+        let past = previous.is_some_and(|last| day < last);
+        self.played += 1;
+        if !past {
+            self.last_day = Some(day);
+        }
+        let Some(guesses) = guesses.filter(|&guesses| guesses >= 1) else {
+            if !past {
+                self.current_streak = 0;
+            }
+            if !self.missed.iter().any(|word| word == secret) {
+                self.missed.push(secret.to_string());
+            }
+            return;
+        };
+        self.won += 1;
+        if self.distribution.len() < guesses {
+            self.distribution.resize(guesses, 0);
+        }
+        self.distribution[guesses - 1] += 1;
+        self.current_streak = match previous {
+            Some(last) if day < last => self.current_streak,
+            Some(last) if day == last => self.current_streak.max(1),
+            Some(last) if day == last + 1 => self.current_streak + 1,
+            _ => 1,
+        };
+        self.max_streak = self.max_streak.max(self.current_streak);
+    }
+
+    /// Forgets a missed secret once it has been solved in practice.
+    pub fn clear_missed(&mut self, secret: &str) {
+        self.missed.retain(|word| word != secret);
+    }
+
+    /// Counts a timed win that took `time`, returning whether it is a new personal best.
+    pub fn record_time(&mut self, time: Duration) -> bool {
+        let best = self.best_time.is_none_or(|best| time < best);
+        if best {
+            self.best_time = Some(time);
+        }
+        best
+    }
+
+    /// Share of games won, from 0 to 1.
+    pub fn win_rate(&self) -> f64 {
+        if self.played == 0 {
+            0.0
+        } else {
+            self.won as f64 / self.played as f64
+        }
+    }
+}
+
+/// Where stats are kept unless another file is given: the per-user data directory.
+pub fn default_stats_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("share")))?;
+    Some(base.join("wordle-solver").join("stats.txt"))
+}
+
+/// The separate ledger for ranked games, kept beside the stats file at `path`:
+/// `stats.txt` becomes `stats-ranked.txt`.
+pub fn ranked_stats_path(path: &Path) -> PathBuf {
+    sibling_path(path, "ranked")
+}
+
+/// The game log kept beside the stats file at `path`: `stats.txt` logs its games
+/// to `stats-games.txt`.
+pub fn game_log_path(path: &Path) -> PathBuf {
+    sibling_path(path, "games")
+}
+
+// The file next to `path` with `-suffix` added to its name before the extension
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default();
+    let name = match path.extension() {
+        Some(extension) => format!("{}-{}.{}", stem, suffix, extension.to_string_lossy()),
+        None => format!("{}-{}", stem, suffix),
+    };
+    path.with_file_name(name)
+}
+
+/// A finished game as kept in the game log.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FinishedGame {
+    /// Day number the game was played for
+    pub day: i64,
+    pub secret: String,
+    pub guesses: Vec<String>,
+}
+
+/// Reads every game logged at `path`, oldest first, or none if nothing has been logged there yet.
+pub fn load_games(path: &str) -> Result<Vec<FinishedGame>> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(source) => return Err(Error::Io { path: path.to_string(), source }),
+    };
+    let invalid = |reason: String| Error::InvalidStats { path: path.to_string(), reason };
+
+    let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+    if lines.next() != Some(GAMES_HEADER) {
+        return Err(invalid(format!("missing '{}' header", GAMES_HEADER)));
+    }
+    lines
+        .map(|line| {
+            let mut fields = line.split_whitespace();
+            let day = fields.next().and_then(|day| day.parse().ok());
+            match (day, fields.next()) {
+                (Some(day), Some(secret)) => {
+                    Ok(FinishedGame { day, secret: secret.to_string(), guesses: fields.map(str::to_string).collect() })
+                }
+                _ => Err(invalid(format!("bad game '{}'", line))),
+            }
+        })
+        .collect()
+}
+
+/// Adds `game` to the end of the log at `path`, starting the log if needed.
+pub fn append_game(path: &str, game: &FinishedGame) -> Result<()> {
+    let io_error = |source| Error::Io { path: path.to_string(), source };
+    if let Some(dir) = PathBuf::from(path).parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let mut text = if Path::new(path).exists() { String::new() } else { format!("{}\n", GAMES_HEADER) };
+    text.push_str(&format!("{} {} {}\n", game.day, game.secret, game.guesses.join(" ")));
+    let mut file = fs::OpenOptions::new().create(true).append(true).open(path).map_err(io_error)?;
+    file.write_all(text.as_bytes()).map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::temp_path;
+
+    fn won_on(days: &[i64]) -> PlayerStats {
+        let mut stats = PlayerStats::default();
+        for &day in days {
+            stats.record(day, "crane", Some(3));
+        }
+        stats
+    }
+
+    #[test]
+    fn save_then_load_gives_back_the_same_stats() {
+        let path = temp_path("stats-round-trip.txt");
+        let stats = PlayerStats {
+            played: 12,
+            won: 10,
+            distribution: vec![0, 2, 4, 3, 1],
+            current_streak: 3,
+            max_streak: 5,
+            last_day: Some(20377),
+            hints: 4,
+            best_time: Some(Duration::from_millis(48210)),
+            missed: vec!["vivid".to_string(), "mamma".to_string()],
+        };
+        stats.save(&path).unwrap();
+        let loaded = PlayerStats::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, stats);
+    }
+
+    #[test]
+    fn missing_file_loads_as_empty_stats() {
+        assert_eq!(PlayerStats::load(&temp_path("stats-missing.txt")).unwrap(), PlayerStats::default());
+    }
+
+    #[test]
+    fn a_win_the_next_day_extends_the_streak() {
+        let stats = won_on(&[100, 101, 102]);
+        assert_eq!((stats.current_streak, stats.max_streak), (3, 3));
+        assert_eq!(stats.last_day, Some(102));
+    }
+
+    #[test]
+    fn a_second_win_the_same_day_does_not_extend_the_streak() {
+        let stats = won_on(&[100, 101, 101]);
+        assert_eq!((stats.current_streak, stats.max_streak), (2, 2));
+        assert_eq!((stats.played, stats.won), (3, 3));
+    }
+
+    #[test]
+    fn a_missed_day_starts_a_new_streak() {
+        let stats = won_on(&[100, 101, 103]);
+        assert_eq!((stats.current_streak, stats.max_streak), (1, 2));
+        assert_eq!(stats.last_day, Some(103));
+    }
+
+    #[test]
+    fn a_game_for_an_earlier_day_leaves_the_streak_and_last_day() {
+        let mut stats = won_on(&[100, 101]);
+        stats.record(99, "crane", Some(3));
+        assert_eq!((stats.current_streak, stats.last_day), (2, Some(101)));
+        stats.record(98, "vivid", None);
+        assert_eq!((stats.current_streak, stats.last_day), (2, Some(101)));
+        assert_eq!((stats.played, stats.won), (4, 3));
+        assert_eq!(stats.missed, ["vivid"]);
+    }
+
+    #[test]
+    fn a_loss_breaks_the_streak() {
+        let mut stats = won_on(&[100, 101]);
+        stats.record(102, "vivid", None);
+        assert_eq!((stats.current_streak, stats.max_streak), (0, 2));
+        stats.record(103, "crane", Some(2));
+        assert_eq!(stats.current_streak, 1);
+    }
+
+    #[test]
+    fn the_ranked_ledger_sits_beside_the_stats_file() {
+        assert_eq!(ranked_stats_path(Path::new("data/stats.txt")), PathBuf::from("data/stats-ranked.txt"));
+        assert_eq!(ranked_stats_path(Path::new("stats")), PathBuf::from("stats-ranked"));
+    }
+
+    #[test]
+    fn logged_games_load_back_in_order() {
+        let path = temp_path("stats-games.txt");
+        assert_eq!(load_games(&path).unwrap(), []);
+        let won = FinishedGame {
+            day: 20377,
+            secret: "crane".to_string(),
+            guesses: vec!["slate".to_string(), "crane".to_string()],
+        };
+        let lost = FinishedGame { day: 20378, secret: "vivid".to_string(), guesses: vec!["irate".to_string()] };
+        append_game(&path, &won).unwrap();
+        append_game(&path, &lost).unwrap();
+        let loaded = load_games(&path);
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), [won, lost]);
+        assert_eq!(game_log_path(Path::new("data/stats.txt")), PathBuf::from("data/stats-games.txt"));
+    }
+}
@@ -0,0 +1,554 @@
+//! Ways of choosing the next guess.
+
+use std::collections::{HashMap, HashSet};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use crate::cancel::CancelToken;
+use crate::oracle::{calculate_entropy, partition_by_pattern, simulate_results, total_weight, word_weight, WordWeights};
+use crate::par::*;
+
+/// How the solver picks its next guess.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Strategy {
+    /// Pick the guess with the highest expected information
+    Entropy,
+    /// Pick the guess whose letters are most common among the candidates
+    Fast,
+    /// Pick the guess with the shortest average game over random simulated continuations
+    Rollout,
+    /// Look a few plies ahead from the highest-entropy guesses and pick the one with the fewest expected guesses
+    Beam,
+    /// Pick the guess with the fewest expected guesses to finish, looking one ply ahead
+    #[cfg_attr(feature = "cli", value(skip))]
+    Expected,
+    /// Pick the guess with the fewest guesses to finish in the worst case, by exhaustive search
+    #[cfg_attr(feature = "cli", value(skip))]
+    Guarantee,
+    /// Pick the candidate with the fewest expected guesses to finish, by exhaustive search over candidate guesses
+    #[cfg_attr(feature = "cli", value(skip))]
+    Exact,
+}
+
+impl Strategy {
+    /// Picks from `guesses` the word that best narrows down `candidates`.
+    ///
+    /// The costlier searches stop early once `cancel` is cancelled, returning the best
+    /// guess among those weighed by then, if any.
+    pub fn best_guess(
+        self,
+        guesses: &[String],
+        candidates: &[String],
+        weights: &WordWeights,
+        beam: BeamSettings,
+        seed: Option<u64>,
+        cancel: &CancelToken,
+    ) -> Option<String> {
+        match self {
+            Strategy::Entropy => best_entropy_guess(guesses, candidates, weights),
+            Strategy::Fast => best_frequency_guess(guesses, candidates, weights),
+            Strategy::Rollout => best_rollout_guess(guesses, candidates, weights, seed, cancel),
+            Strategy::Beam => best_beam_guess(guesses, candidates, weights, beam, cancel),
+            Strategy::Expected => best_expected_guess(guesses, candidates, weights, cancel),
+            Strategy::Guarantee => best_guaranteed_guess(guesses, candidates, cancel),
+            Strategy::Exact => best_exact_candidate(candidates, weights, cancel),
+        }
+    }
+
+    /// A short human-readable name.
+    pub fn label(self) -> &'static str {
+        match self {
+            Strategy::Entropy => "entropy",
+            Strategy::Fast => "fast",
+            Strategy::Rollout => "rollout",
+            Strategy::Beam => "beam search",
+            Strategy::Expected => "expected guesses",
+            Strategy::Guarantee => "worst-case guarantee",
+            Strategy::Exact => "exact expected guesses among candidates",
+        }
+    }
+}
+
+/// How many guesses the beam planner keeps at each ply, and how many plies it looks ahead.
+#[derive(Clone, Copy, Debug)]
+pub struct BeamSettings {
+    pub width: usize,
+    pub depth: usize,
+}
+
+impl Default for BeamSettings {
+    fn default() -> Self {
+        Self { width: 5, depth: 2 }
+    }
+}
+
+/// Candidate counts below which adaptive play switches to a costlier, more exact objective.
+#[derive(Clone, Copy, Debug)]
+pub struct PhaseThresholds {
+    pub expected_below: usize,
+    pub guarantee_below: usize,
+}
+
+pub(crate) fn best_entropy_guess(guesses: &[String], candidates: &[String], weights: &WordWeights) -> Option<String> {
+    // Among equally informative guesses, prefer one that could be the answer
+    let candidate_set: HashSet<&str> = candidates.iter().map(String::as_str).collect();
+    guesses
+        .par_iter() // Use Rayon's parallel iterator
+        .map(|word| (word, calculate_entropy(word, candidates, weights), candidate_set.contains(word.as_str())))
+        .max_by(|(_, entropy_a, possible_a), (_, entropy_b, possible_b)| {
+            entropy_a.partial_cmp(entropy_b).unwrap().then(possible_a.cmp(possible_b))
+        })
+        .map(|(word, _, _)| word.clone())
+}
+
+fn best_frequency_guess(guesses: &[String], candidates: &[String], weights: &WordWeights) -> Option<String> {
+    // Weigh how often each letter appears at each position, and in each word at all
+    let word_length = candidates.first().map_or(0, String::len);
+    let mut positional_counts = vec![[0.0f64; 26]; word_length];
+    let mut presence_counts = [0.0f64; 26];
+    for word in candidates {
+        let weight = word_weight(weights, word);
+        let mut seen = [false; 26];
+        for (idx, letter) in word.bytes().enumerate() {
+            let letter = (letter - b'a') as usize;
+            positional_counts[idx][letter] += weight;
+            if !seen[letter] {
+                seen[letter] = true;
+                presence_counts[letter] += weight;
+            }
+        }
+    }
+
+    // Repeated letters only earn their presence score once
+    let score = |word: &str| -> f64 {
+        let mut seen = [false; 26];
+        word.bytes().enumerate().fold(0.0, |acc, (idx, letter)| {
+            let letter = (letter - b'a') as usize;
+            let presence = if seen[letter] { 0.0 } else { presence_counts[letter] };
+            seen[letter] = true;
+            acc + positional_counts[idx][letter] + presence
+        })
+    };
+
+    // Letter frequency says nothing about the last couple of words, so guess the likelier one
+    if candidates.len() <= 2 {
+        return candidates
+            .iter()
+            .max_by(|a, b| word_weight(weights, a).partial_cmp(&word_weight(weights, b)).unwrap())
+            .cloned();
+    }
+    guesses
+        .iter()
+        .max_by(|a, b| score(a).partial_cmp(&score(b)).unwrap())
+        .cloned()
+}
+
+fn best_expected_guess(guesses: &[String], candidates: &[String], weights: &WordWeights, cancel: &CancelToken) -> Option<String> {
+    let candidate_set: HashSet<&str> = candidates.iter().map(String::as_str).collect();
+    let candidates_weight = total_weight(candidates, weights);
+
+    // Look ahead over the candidates alone so that a large guess pool stays affordable
+    let cost = |word: &str| -> f64 {
+        1.0 + partition_by_pattern(word, candidates)
+            .iter()
+            .filter(|(pattern, _)| !pattern.chars().all(|c| c == 'G'))
+            .map(|(_, bucket)| {
+                let probability = total_weight(bucket, weights) / candidates_weight;
+                probability * expected_guesses(bucket, None, weights, Strategy::Entropy, BeamSettings::default(), None, 1, cancel)
+            })
+            .sum::<f64>()
+    };
+
+    guesses
+        .par_iter()
+        .filter(|_| !cancel.is_cancelled())
+        .map(|word| (word, cost(word), candidate_set.contains(word.as_str())))
+        .min_by(|(_, cost_a, possible_a), (_, cost_b, possible_b)| {
+            cost_a.partial_cmp(cost_b).unwrap().then(possible_b.cmp(possible_a))
+        })
+        .map(|(word, _, _)| word.clone())
+}
+
+fn best_beam_guess(
+    guesses: &[String],
+    candidates: &[String],
+    weights: &WordWeights,
+    beam: BeamSettings,
+    cancel: &CancelToken,
+) -> Option<String> {
+    let candidate_set: HashSet<&str> = candidates.iter().map(String::as_str).collect();
+    beam_shortlist(guesses, candidates, weights, beam.width)
+        .into_par_iter()
+        .filter(|_| !cancel.is_cancelled())
+        .map(|word| (word, beam_guess_cost(word, candidates, weights, beam, beam.depth.max(1)), candidate_set.contains(word.as_str())))
+        .min_by(|(_, cost_a, possible_a), (_, cost_b, possible_b)| {
+            cost_a.partial_cmp(cost_b).unwrap().then(possible_b.cmp(possible_a))
+        })
+        .map(|(word, _, _)| word.clone())
+}
+
+// The `width` highest-entropy guesses, possible answers first among equals
+fn beam_shortlist<'a>(guesses: &'a [String], candidates: &[String], weights: &WordWeights, width: usize) -> Vec<&'a String> {
+    let candidate_set: HashSet<&str> = candidates.iter().map(String::as_str).collect();
+    let mut scored: Vec<(&String, f64, bool)> = guesses
+        .par_iter()
+        .map(|word| (word, calculate_entropy(word, candidates, weights), candidate_set.contains(word.as_str())))
+        .collect();
+    scored.sort_by(|(word_a, entropy_a, possible_a), (word_b, entropy_b, possible_b)| {
+        entropy_b.partial_cmp(entropy_a).unwrap().then(possible_b.cmp(possible_a)).then(word_a.cmp(word_b))
+    });
+    scored.into_iter().take(width).map(|(word, _, _)| word).collect()
+}
+
+// Expected guesses to finish when guessing `word` now and searching `depth` plies in total.
+// Later plies only consider candidates, which keeps large guess pools affordable.
+fn beam_guess_cost(word: &str, candidates: &[String], weights: &WordWeights, beam: BeamSettings, depth: usize) -> f64 {
+    let candidates_weight = total_weight(candidates, weights);
+    1.0 + partition_by_pattern(word, candidates)
+        .iter()
+        .filter(|(pattern, _)| !pattern.chars().all(|c| c == 'G'))
+        .map(|(_, bucket)| {
+            let probability = total_weight(bucket, weights) / candidates_weight;
+            let remaining = match bucket.len() {
+                1 => 1.0,
+                count if depth <= 1 => estimated_guesses(count),
+                _ => beam_shortlist(bucket, bucket, weights, beam.width)
+                    .into_iter()
+                    .map(|next| beam_guess_cost(next, bucket, weights, beam, depth - 1))
+                    .fold(f64::INFINITY, f64::min),
+            };
+            probability * remaining
+        })
+        .sum::<f64>()
+}
+
+// Optimal among guesses that could be the answer, never probes; exponential, so only for small sets
+fn best_exact_candidate(candidates: &[String], weights: &WordWeights, cancel: &CancelToken) -> Option<String> {
+    let mut memo = HashMap::new();
+    exact_candidate_search(candidates, weights, &mut memo, f64::INFINITY, cancel).map(|(word, _)| word)
+}
+
+// Fewest expected guesses to find the answer among `candidates`, guessing only candidates
+fn exact_expected_guesses(
+    candidates: &[String],
+    weights: &WordWeights,
+    memo: &mut HashMap<Vec<String>, f64>,
+    cancel: &CancelToken,
+) -> f64 {
+    if candidates.len() <= 1 {
+        return candidates.len() as f64;
+    }
+    if let Some(&cost) = memo.get(candidates) {
+        return cost;
+    }
+    let cost = exact_candidate_search(candidates, weights, memo, f64::INFINITY, cancel).map_or(f64::INFINITY, |(_, cost)| cost);
+    memo.insert(candidates.to_vec(), cost);
+    cost
+}
+
+// Best candidate to guess next and its expected cost, unless nothing beats `bound`; probes
+// outside `candidates` are never tried.
+// Trying high-entropy guesses first finds good bounds early and prunes the rest.
+fn exact_candidate_search(
+    candidates: &[String],
+    weights: &WordWeights,
+    memo: &mut HashMap<Vec<String>, f64>,
+    bound: f64,
+    cancel: &CancelToken,
+) -> Option<(String, f64)> {
+    let mut order: Vec<(&String, f64)> = candidates
+        .iter()
+        .map(|word| (word, calculate_entropy(word, candidates, weights)))
+        .collect();
+    order.sort_by(|(word_a, entropy_a), (word_b, entropy_b)| {
+        entropy_b.partial_cmp(entropy_a).unwrap().then(word_a.cmp(word_b))
+    });
+
+    let candidates_weight = total_weight(candidates, weights);
+    let mut best: Option<(String, f64)> = None;
+    let mut best_cost = bound;
+    'guesses: for (word, _) in order {
+        if cancel.is_cancelled() {
+            break;
+        }
+        let buckets: Vec<(f64, Vec<String>)> = partition_by_pattern(word, candidates)
+            .into_iter()
+            .filter(|(pattern, _)| !pattern.chars().all(|c| c == 'G'))
+            .map(|(_, bucket)| (total_weight(&bucket, weights) / candidates_weight, bucket))
+            .collect();
+        if buckets.iter().any(|(_, bucket)| bucket.len() == candidates.len()) {
+            continue;
+        }
+
+        // Start from a lower bound for every bucket and tighten it one bucket at a time:
+        // a bucket costs at least one guess, or two unless its likeliest word comes first
+        let lower_bound = |probability: f64, bucket: &[String]| -> f64 {
+            if bucket.len() == 1 {
+                return probability;
+            }
+            let heaviest = bucket.iter().map(|word| word_weight(weights, word)).fold(0.0, f64::max);
+            probability * (2.0 - heaviest / total_weight(bucket, weights))
+        };
+        let mut cost = 1.0 + buckets.iter().map(|(probability, bucket)| lower_bound(*probability, bucket)).sum::<f64>();
+        for (probability, bucket) in &buckets {
+            if cost >= best_cost {
+                continue 'guesses;
+            }
+            if bucket.len() > 1 {
+                cost += probability * exact_expected_guesses(bucket, weights, memo, cancel) - lower_bound(*probability, bucket);
+            }
+        }
+        if cost < best_cost {
+            best_cost = cost;
+            best = Some((word.clone(), cost));
+        }
+    }
+    best
+}
+
+fn best_guaranteed_guess(guesses: &[String], candidates: &[String], cancel: &CancelToken) -> Option<String> {
+    let candidate_set: HashSet<&str> = candidates.iter().map(String::as_str).collect();
+    guesses
+        .par_iter()
+        .filter(|_| !cancel.is_cancelled())
+        .map(|word| (word, guaranteed_guesses_with(word, candidates, usize::MAX, cancel), candidate_set.contains(word.as_str())))
+        .min_by(|(_, worst_a, possible_a), (_, worst_b, possible_b)| {
+            worst_a.cmp(worst_b).then(possible_b.cmp(possible_a))
+        })
+        .map(|(word, _, _)| word.clone())
+}
+
+// Fewest guesses that always find the answer among `candidates`, guessing only candidates
+fn guaranteed_guesses(candidates: &[String], cancel: &CancelToken) -> usize {
+    if candidates.len() <= 1 {
+        return candidates.len();
+    }
+
+    // Nothing beats guessing a word that splits the rest into singletons
+    let lower_bound = 2;
+    let mut best = usize::MAX;
+    for word in candidates {
+        best = best.min(guaranteed_guesses_with(word, candidates, best, cancel));
+        if best == lower_bound || cancel.is_cancelled() {
+            break;
+        }
+    }
+    best
+}
+
+// Worst-case guesses when opening with `word`, giving up once it can't beat `cutoff`
+fn guaranteed_guesses_with(word: &str, candidates: &[String], cutoff: usize, cancel: &CancelToken) -> usize {
+    let mut worst = 1;
+    for (pattern, bucket) in partition_by_pattern(word, candidates) {
+        if pattern.chars().all(|c| c == 'G') {
+            continue;
+        }
+        // A guess that doesn't narrow anything down can never finish
+        if bucket.len() == candidates.len() {
+            return usize::MAX;
+        }
+        worst = worst.max(1 + guaranteed_guesses(&bucket, cancel));
+        if worst >= cutoff {
+            return worst;
+        }
+    }
+    worst
+}
+
+/// What to do when several guesses tie for best.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum TiePolicy {
+    /// Take the alphabetically first of the tied guesses
+    First,
+    /// Take the tied guess with the highest word-list weight
+    Frequent,
+    /// List the tied guesses and let the player choose
+    Prompt,
+}
+
+/// When guesses that can't be the answer, probes, may be suggested.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum ProbePolicy {
+    /// Whenever a probe gives the most information
+    Always,
+    /// Until the last guess, which is always a possible answer
+    NotLast,
+    /// Never; only possible answers are suggested
+    Never,
+}
+
+// How many random secrets each guess is played out against
+const ROLLOUTS_PER_GUESS: usize = 32;
+
+// With a seed, each guess draws its secrets from its own generator seeded by the
+// seed and the guess, so results don't depend on how rayon schedules the work
+fn best_rollout_guess(
+    guesses: &[String],
+    candidates: &[String],
+    weights: &WordWeights,
+    seed: Option<u64>,
+    cancel: &CancelToken,
+) -> Option<String> {
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let candidate_set: HashSet<&str> = candidates.iter().map(String::as_str).collect();
+    guesses
+        .par_iter()
+        .filter(|_| !cancel.is_cancelled())
+        .map(|word| {
+            let average = match seed {
+                Some(seed) => {
+                    let word_seed = word.bytes().fold(seed, |acc, byte| acc.wrapping_mul(31).wrapping_add(byte as u64));
+                    average_rollout_length(word, candidates, weights, &mut StdRng::seed_from_u64(word_seed))
+                }
+                None => average_rollout_length(word, candidates, weights, &mut rand::thread_rng()),
+            };
+            (word, average, candidate_set.contains(word.as_str()))
+        })
+        .min_by(|(_, length_a, possible_a), (_, length_b, possible_b)| {
+            length_a.partial_cmp(length_b).unwrap().then(possible_b.cmp(possible_a))
+        })
+        .map(|(word, _, _)| word.clone())
+}
+
+fn average_rollout_length<R: Rng>(word: &str, candidates: &[String], weights: &WordWeights, rng: &mut R) -> f64 {
+    let total_guesses: usize = (0..ROLLOUTS_PER_GUESS)
+        .map(|_| {
+            let secret = candidates.choose_weighted(&mut *rng, |word| word_weight(weights, word)).unwrap();
+            rollout_length(word, secret, candidates, weights)
+        })
+        .sum();
+    total_guesses as f64 / ROLLOUTS_PER_GUESS as f64
+}
+
+// Number of guesses to find `secret` when opening with `first_guess` and then
+// greedily playing the fast heuristic over the remaining candidates
+fn rollout_length(first_guess: &str, secret: &str, candidates: &[String], weights: &WordWeights) -> usize {
+    let mut remaining = candidates.to_vec();
+    let mut guess = first_guess.to_string();
+    let mut guesses = 1;
+
+    loop {
+        let pattern = simulate_results(&guess, secret);
+        if pattern.chars().all(|c| c == 'G') {
+            return guesses;
+        }
+        remaining.retain(|word| simulate_results(&guess, word) == pattern);
+        guess = best_frequency_guess(&remaining, &remaining, weights).unwrap();
+        guesses += 1;
+    }
+}
+
+// Rough guesses-to-finish once a search runs out of depth: assume each further
+// guess leaves roughly a third of the candidates
+fn estimated_guesses(count: usize) -> f64 {
+    1.0 + (count as f64).log(3.0)
+}
+
+// How many plies of the solver's own play to expand before falling back to a rough estimate
+pub(crate) const EXPECTATION_DEPTH: usize = 2;
+
+// `guess_list` is the allowed-guess pool; without one the solver guesses from the candidates
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn expected_guesses(
+    candidates: &[String],
+    guess_list: Option<&[String]>,
+    weights: &WordWeights,
+    strategy: Strategy,
+    beam: BeamSettings,
+    seed: Option<u64>,
+    depth: usize,
+    cancel: &CancelToken,
+) -> f64 {
+    let guess = match candidates.len() {
+        0 => return 0.0,
+        1 => return 1.0,
+        count if depth == 0 => return estimated_guesses(count),
+        // Falls back to the rough estimate once cancelled, when no guess may be found
+        count => match strategy.best_guess(guess_list.unwrap_or(candidates), candidates, weights, beam, seed, cancel) {
+            Some(guess) => guess,
+            None => return estimated_guesses(count),
+        },
+    };
+    let candidates_weight = total_weight(candidates, weights);
+
+    // One guess now, plus the weighted cost of every bucket that isn't the win
+    1.0 + partition_by_pattern(&guess, candidates)
+        .iter()
+        .filter(|(pattern, _)| !pattern.chars().all(|c| c == 'G'))
+        .map(|(_, bucket)| {
+            let probability = total_weight(bucket, weights) / candidates_weight;
+            probability * expected_guesses(bucket, guess_list, weights, strategy, beam, seed, depth - 1, cancel)
+        })
+        .sum::<f64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+
+    #[test]
+    fn fast_guess_covers_the_commonest_letters() {
+        let words = test_util::words(&["fight", "light", "might", "sight", "tight", "crane"]);
+        let guesses = test_util::words(&["fight", "crane", "zzzzz"]);
+        let guess = best_frequency_guess(&guesses, &words, &WordWeights::default());
+        assert_eq!(guess.as_deref(), Some("fight"));
+    }
+
+    #[test]
+    fn exact_guess_is_optimal_among_candidates() {
+        // Twenty words, the default --exact-below, with a family that punishes greedy picks
+        let words = test_util::words(&[
+            "fight", "light", "might", "night", "right", "sight", "tight", "wight", "eight", "bight",
+            "break", "bread", "cream", "dream", "crane", "irate", "great", "treat", "slimy", "stare",
+        ]);
+        let weights = WordWeights::default();
+        let cancel = CancelToken::new();
+        let mut memo = HashMap::new();
+        let mut cost_of = |word: &str| -> f64 {
+            1.0 + partition_by_pattern(word, &words)
+                .iter()
+                .filter(|(pattern, _)| !pattern.chars().all(|c| c == 'G'))
+                .map(|(_, bucket)| bucket.len() as f64 / words.len() as f64 * exact_expected_guesses(bucket, &weights, &mut memo, &cancel))
+                .sum::<f64>()
+        };
+        let best = best_exact_candidate(&words, &weights, &cancel).unwrap();
+        let best_cost = cost_of(&best);
+        for word in &words {
+            assert!(best_cost <= cost_of(word) + 1e-9, "{} beats {}", word, best);
+        }
+    }
+
+    #[test]
+    fn seeded_rollouts_repeat_their_pick() {
+        let words = test_util::words(&["break", "bread", "cream", "dream", "crane", "irate", "great", "treat", "slimy", "stare"]);
+        let weights = WordWeights::default();
+        let cancel = CancelToken::new();
+        let pick = |seed| best_rollout_guess(&words, &words, &weights, Some(seed), &cancel);
+        for seed in [1, 2, 3] {
+            assert_eq!(pick(seed), pick(seed), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn searches_stop_once_cancelled() {
+        let words = test_util::words(&["break", "bread", "cream", "dream", "crane", "irate", "great", "treat"]);
+        let weights = WordWeights::default();
+        let cancel = CancelToken::new();
+        for strategy in [Strategy::Rollout, Strategy::Beam, Strategy::Expected, Strategy::Guarantee, Strategy::Exact] {
+            let guess = strategy.best_guess(&words, &words, &weights, BeamSettings::default(), Some(1), &cancel);
+            assert!(guess.is_some(), "{}", strategy.label());
+        }
+        cancel.cancel();
+        for strategy in [Strategy::Rollout, Strategy::Beam, Strategy::Expected, Strategy::Guarantee, Strategy::Exact] {
+            let guess = strategy.best_guess(&words, &words, &weights, BeamSettings::default(), Some(1), &cancel);
+            assert_eq!(guess, None, "{}", strategy.label());
+        }
+    }
+}
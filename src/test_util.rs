@@ -0,0 +1,14 @@
+//! Fixtures shared by the in-module tests.
+
+/// Owned copies of `list`, as word lists are held.
+pub(crate) fn words(list: &[&str]) -> Vec<String> {
+    list.iter().map(|word| word.to_string()).collect()
+}
+
+/// A path under the temp directory, unique to this test run and `name`.
+pub(crate) fn temp_path(name: &str) -> String {
+    std::env::temp_dir()
+        .join(format!("wordle-solver-{}-{}", std::process::id(), name))
+        .to_string_lossy()
+        .into_owned()
+}
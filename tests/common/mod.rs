@@ -0,0 +1,18 @@
+//! Fixtures shared by the integration tests. Each test crate uses only some of them.
+#![allow(dead_code)]
+
+/// The word list the integration tests play over.
+pub fn words() -> Vec<String> {
+    ["break", "bread", "cream", "dream", "crane", "irate", "arena", "great", "treat", "slimy", "stare", "spoon"]
+        .iter()
+        .map(|word| word.to_string())
+        .collect()
+}
+
+/// A path under the temp directory, unique to this test run and `name`.
+pub fn temp_path(name: &str) -> String {
+    std::env::temp_dir()
+        .join(format!("wordle-solver-{}-{}", std::process::id(), name))
+        .to_string_lossy()
+        .into_owned()
+}
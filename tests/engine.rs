@@ -0,0 +1,87 @@
+//! Drives the library through its public API the way a front end would.
+
+use solver_project::oracle::simulate_results;
+use solver_project::{Error, Game, GameStatus, SolverEngine, WordleSolver};
+
+mod common;
+
+use common::words;
+
+fn solver(hard_mode: bool) -> WordleSolver {
+    let mut solver = WordleSolver::builder_from(words()).hard_mode(hard_mode).build().unwrap();
+    solver.start();
+    solver
+}
+
+#[test]
+fn solves_a_known_secret_through_the_engine_trait() {
+    let mut engine: Box<dyn SolverEngine> = Box::new(solver(false));
+    let mut turns = 0;
+    while let Some(guess) = engine.suggest().map(str::to_string) {
+        turns += 1;
+        let status = engine.apply(&guess, &simulate_results(&guess, "break")).unwrap();
+        if status == GameStatus::Won {
+            assert_eq!(guess, "break");
+            break;
+        }
+        assert!(engine.candidates().iter().any(|word| word == "break"));
+    }
+    assert_eq!(engine.status(), GameStatus::Won);
+    assert!(turns <= 6);
+}
+
+#[test]
+fn apply_rejects_malformed_words() {
+    let mut engine = solver(false);
+    assert!(matches!(engine.apply("cranes", "BBBBB"), Err(Error::InvalidWord { .. })));
+    assert!(matches!(engine.apply("cr4ne", "BBBBB"), Err(Error::InvalidWord { .. })));
+    assert!(engine.turns().is_empty());
+}
+
+#[test]
+fn apply_rejects_bad_results() {
+    let mut engine = solver(false);
+    let suggested = engine.current_guess().map(str::to_string);
+    assert!(matches!(engine.apply("crane", "BGYB"), Err(Error::InvalidResults { .. })));
+    assert!(matches!(engine.apply("crane", "BGYBX"), Err(Error::InvalidResults { .. })));
+    assert!(engine.turns().is_empty());
+    // The refused guess doesn't replace the suggestion
+    assert_eq!(engine.current_guess().map(str::to_string), suggested);
+}
+
+#[test]
+fn apply_rejects_hard_mode_violations() {
+    let mut engine = solver(true);
+    engine.apply("crane", &simulate_results("crane", "break")).unwrap();
+    // The R is known to be second, and the A and E must be reused
+    match engine.apply("slimy", "BBBBB") {
+        Err(Error::HardMode { word, .. }) => assert_eq!(word, "slimy"),
+        other => panic!("expected a hard-mode error, got {:?}", other.map(|_| ())),
+    }
+    assert!(engine.apply("bread", &simulate_results("bread", "break")).is_ok());
+}
+
+#[test]
+fn apply_rejects_turns_after_a_win() {
+    let mut engine = solver(false);
+    assert_eq!(engine.apply("break", "GGGGG").unwrap(), GameStatus::Won);
+    assert!(matches!(engine.apply("crane", "BBBBB"), Err(Error::GameOver)));
+    assert_eq!(engine.status(), GameStatus::Won);
+    assert_eq!(engine.turns().len(), 1);
+}
+
+#[test]
+fn game_rejects_words_outside_its_list() {
+    let mut game = Game::new("break", words(), 6);
+    assert!(matches!(game.guess("zebra"), Err(Error::UnknownWord(word)) if word == "zebra"));
+    assert!(game.turns().is_empty());
+}
+
+#[test]
+fn cancelled_batches_play_no_games() {
+    let mut solver = solver(false);
+    solver.cancel_token().cancel();
+    assert!(solver.play_games(&words()).is_empty());
+    solver.cancel_token().reset();
+    assert_eq!(solver.play_games(&words()).len(), words().len());
+}
@@ -0,0 +1,78 @@
+//! Saving a game and resuming it through the public API.
+
+use std::fs;
+use solver_project::oracle::simulate_results;
+use solver_project::{Error, SolverEngine, WordleSolver};
+
+mod common;
+
+use common::{temp_path, words};
+
+fn solver(words: Vec<String>) -> WordleSolver {
+    let mut solver = WordleSolver::builder_from(words).build().unwrap();
+    solver.start();
+    solver
+}
+
+#[test]
+fn saved_session_resumes_where_it_left_off() {
+    let path = temp_path("round-trip");
+    let mut saved = solver(words());
+    saved.apply("crane", &simulate_results("crane", "break")).unwrap();
+    saved.apply("bread", &simulate_results("bread", "break")).unwrap();
+    saved.save_session(&path).unwrap();
+
+    let mut resumed = solver(words());
+    resumed.load_session(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let turns = |solver: &WordleSolver| -> Vec<(String, String)> {
+        solver.turns().iter().map(|turn| (turn.guess.clone(), turn.results.clone())).collect()
+    };
+    assert_eq!(turns(&resumed), turns(&saved));
+    assert_eq!(resumed.candidates(), saved.candidates());
+    assert_eq!(resumed.current_guess(), saved.current_guess());
+    assert_eq!(resumed.attempts(), 2);
+}
+
+#[test]
+fn session_from_another_word_list_is_rejected() {
+    let path = temp_path("mismatch");
+    let mut saved = solver(words());
+    saved.apply("crane", &simulate_results("crane", "break")).unwrap();
+    saved.save_session(&path).unwrap();
+
+    let mut other_words = words();
+    other_words.push("zesty".to_string());
+    let mut other = solver(other_words);
+    let result = other.load_session(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert!(matches!(result, Err(Error::SessionMismatch(_))), "{:?}", result.err());
+    assert!(other.turns().is_empty());
+}
+
+#[test]
+fn transcript_replays_with_the_same_suggestions() {
+    let path = temp_path("transcript");
+    let mut played = solver(words());
+    let opener = played.current_guess().unwrap().to_string();
+    played.next_turn(&simulate_results(&opener, "break")).unwrap();
+    let suggested = played.current_guess().unwrap().to_string();
+    played.apply("bread", &simulate_results("bread", "break")).unwrap();
+    played.export_transcript(&path).unwrap();
+
+    let steps = solver(words()).replay_transcript(&path);
+    let mut other_words = words();
+    other_words.push("zesty".to_string());
+    let mismatch = solver(other_words).replay_transcript(&path);
+    fs::remove_file(&path).unwrap();
+
+    let steps = steps.unwrap();
+    let guesses: Vec<&str> = steps.iter().map(|step| step.guess.as_str()).collect();
+    assert_eq!(guesses, [opener.as_str(), "bread"]);
+    // The transcript keeps the solver's own pick even when another word was played
+    assert_eq!(steps[1].recorded.as_deref(), Some(suggested.as_str()));
+    assert!(steps.iter().all(|step| step.matches()));
+    assert!(matches!(mismatch, Err(Error::SessionMismatch(_))), "{:?}", mismatch.err());
+}